@@ -1,25 +1,101 @@
+use std::borrow::Cow;
+use std::mem;
+
 use macroific::elements::GenericImpl;
 use macroific::prelude::*;
 use proc_macro2::{Delimiter, Group, Ident, TokenStream};
-use quote::{format_ident, quote, ToTokens, TokenStreamExt};
+use quote::{quote, ToTokens, TokenStreamExt};
+use syn::ext::IdentExt;
 use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
-use syn::{Attribute, DeriveInput, Generics, Token, Type};
+use syn::spanned::Spanned;
+use syn::{
+    Attribute, Data, DeriveInput, FnArg, Generics, ItemFn, Member, Pat, PatType, Signature, Token,
+    Type,
+};
 
+use case::{is_ident, to_ident, to_snake_case};
 use options::*;
 use parsed_field::*;
+use parsed_variant::*;
+use struct_shape::{Retyped, StructShape};
 
 use crate::derive_accessors::final_options::{FinalOptions, Naming};
 
+mod case;
 mod final_options;
 pub mod options;
 mod parsed_field;
+mod parsed_variant;
+mod struct_shape;
+mod types;
 
 const ATTR_NAME: &str = "access";
 
+macro_rules! variations {
+    (
+        [
+            $final_opts: ident,
+            $container_opts: ident,
+            $container_defaults: ident,
+            $naming: ident,
+            $opts: ident,
+            $tokens: ident,
+            $name_base: ident,
+            $struct_name: ident,
+            $comments: ident
+        ] => $([
+            $lower: ident $upper: ident $(#[$attr: meta])? $(unsafe($safety: expr))?
+            $(const($const_fn: expr))? $(defaults($defaults: expr))? $(naming($naming_override: expr))?
+            $(enable($enable: expr))? =>
+            $render: ident($($arg: expr),+)
+        ]),+
+    ) => {
+        $(
+            match $final_opts::new(
+                variations!(@or $container_opts.$lower $(, $enable)?),
+                variations!(@or &$container_defaults.$lower $(, $defaults)?),
+                variations!(@or &$naming::$upper $(, $naming_override)?),
+                $opts.$lower,
+                $opts.all.as_ref(),
+                &$container_defaults.all,
+            ) {
+                #[allow(unused_mut)]
+                Some(mut opts) if !opts.skip => {
+                    $(opts.safety.clone_from(&$safety);)?
+                    $(opts.const_fn &= $const_fn;)?
+                    let attr = variations!(@attr $(#[$attr])?);
+                    match accessor_name(&$name_base, &$struct_name, &opts) {
+                        Ok(name) => {
+                            let aliases = alias_headers(&name, &opts, attr.as_ref());
+
+                            render_common($tokens, &$comments, &opts, attr);
+                            $tokens.append(name.clone());
+
+                            let rest = $render($($arg,)+ opts);
+                            let aliases = render_aliases(&name, aliases, &rest);
+                            $tokens.extend(rest);
+                            $tokens.extend(aliases);
+                        }
+                        Err(e) => $tokens.extend(e.into_compile_error()),
+                    }
+                },
+                _ => {},
+            }
+        )+
+    };
+    (@attr) => { None };
+    (@attr #[$attr: meta]) => { Some(::syn::parse_quote!(#[$attr])) };
+    (@or $default: expr) => { $default };
+    (@or $default: expr, $override: expr) => { $override };
+}
+
 pub struct DeriveAccessors {
     fields: Vec<ParsedField>,
+    variants: Vec<ParsedVariant>,
+    struct_shape: Option<StructShape>,
     container_opts: ContainerOptions,
+    when_rules: Vec<WhenRule>,
     ident: Ident,
     generics: Generics,
 }
@@ -33,71 +109,26 @@ impl ToTokens for DeriveAccessors {
     where
         Self: Sized,
     {
-        macro_rules! variations {
-            (
-                [
-                    $final_opts: ident,
-                    $container_opts: ident,
-                    $naming: ident,
-                    $opts: ident,
-                    $tokens: ident,
-                    $ident: ident,
-                    $comments: ident,
-                    $ty: ident
-                ] => $([$lower: ident $upper: ident $render: ident]),+
-                | [$last_lower: ident $last_upper: ident $last_render: ident]
-            ) => {
-                $(
-                    match $final_opts::new(
-                        $container_opts.$lower,
-                        &$container_opts.defaults.$lower,
-                        &$naming::$upper,
-                        $opts.$lower,
-                        $opts.all.as_ref(),
-                        &$container_opts.defaults.all,
-                    ) {
-                        Some(opts) if !opts.skip => {
-                            let a = ::syn::parse_quote!(#[must_use]);
-                            render_common(&mut $tokens, &$ident, &$comments, &opts, Some(a));
-                            $tokens.extend($render(&$ident, &$ty, opts));
-                        },
-                        _ => {},
-                    }
-                )+
-
-                match $final_opts::new(
-                    $container_opts.$last_lower,
-                    &$container_opts.defaults.$last_lower,
-                    &$naming::$last_upper,
-                    $opts.$last_lower,
-                    $opts.all.as_ref(),
-                    &$container_opts.defaults.all,
-                ) {
-                    Some(opts) if !opts.skip => {
-                        render_common(&mut $tokens, &$ident, &$comments, &opts, None);
-                        $tokens.extend($last_render($ident, $ty, opts));
-                    },
-                    _ => {},
-                }
-            };
-        }
-
         let Self {
             fields,
-            container_opts,
+            variants,
+            struct_shape,
+            mut container_opts,
+            when_rules,
             ident,
             mut generics,
         } = self;
 
+        let struct_name = to_snake_case(&ident.unraw().to_string());
         let mut out = {
             if !container_opts.bounds.is_empty() {
                 generics
                     .make_where_clause()
                     .predicates
-                    .extend(container_opts.bounds);
+                    .extend(mem::take(&mut container_opts.bounds));
             }
 
-            let header = GenericImpl::new(generics).with_target(ident);
+            let header = GenericImpl::new(generics.clone()).with_target(ident);
             quote! {
                 #[automatically_derived]
                 #[allow(clippy::all)]
@@ -109,22 +140,25 @@ impl ToTokens for DeriveAccessors {
             let mut tokens = TokenStream::new();
 
             for field in fields {
-                let ParsedField {
-                    comments,
-                    opts,
-                    ident,
-                    ty,
-                } = field;
-
-                if opts.skip {
-                    continue;
-                }
+                render_field(
+                    &mut tokens,
+                    field,
+                    &container_opts,
+                    &when_rules,
+                    &struct_name,
+                    struct_shape.as_ref(),
+                    &generics,
+                );
+            }
 
-                variations!(
-                    [FinalOptions, container_opts, Naming, opts, tokens, ident, comments, ty] =>
-                    [get GET RENDER_GET],
-                    [get_mut GET_MUT RENDER_GET_MUT]
-                    | [set SET RENDER_SET]
+            let catch_all = variants.len() > 1;
+            for variant in variants {
+                render_variant(
+                    &mut tokens,
+                    variant,
+                    &container_opts,
+                    &struct_name,
+                    catch_all,
                 );
             }
 
@@ -135,34 +169,327 @@ impl ToTokens for DeriveAccessors {
     }
 }
 
+fn render_field(
+    tokens: &mut TokenStream,
+    field: ParsedField,
+    container_opts: &ContainerOptions,
+    when_rules: &[WhenRule],
+    struct_name: &str,
+    struct_shape: Option<&StructShape>,
+    generics: &Generics,
+) {
+    let name_base = match field.name_base(container_opts) {
+        Ok(name_base) => name_base,
+        Err(e) => {
+            tokens.extend(e.into_compile_error());
+            return;
+        }
+    };
+    let is_bool = field.is_bool();
+    let is_option = field.is_option(container_opts);
+    let smart_get_defaults = field.smart_get_defaults(container_opts, when_rules);
+    let ParsedField {
+        comments,
+        opts,
+        access,
+        ty,
+    } = field;
+
+    if opts.skip {
+        return;
+    }
+
+    let safety = if access.union {
+        Some(format!(
+            " `{}` must be the union's active field",
+            access.member.to_token_stream()
+        ))
+    } else {
+        None
+    };
+
+    // Defaults inferred from the field's type sit between `when` rules & the container's defaults
+    let mut container_defaults = Cow::Borrowed(&container_opts.defaults);
+    if let Some(smart) = smart_get_defaults {
+        let defaults = container_defaults.to_mut();
+        defaults.get = smart.or(&defaults.get);
+        defaults.get_bool = smart.or(&defaults.get_bool);
+    }
+
+    // The `option` family defaults to `Option<&T>` getters & setters wrapping values in `Some`
+    if is_option {
+        let as_ref = VariationDefaults {
+            as_ref: Some(true),
+            ..Default::default()
+        };
+        let some = VariationDefaults {
+            some: Some(true),
+            ..Default::default()
+        };
+        let defaults = container_defaults.to_mut();
+        defaults.get = as_ref.or(&defaults.get);
+        defaults.set = some.or(&defaults.set);
+        defaults.with = some.or(&defaults.with);
+    }
+
+    // Matching `when` rules sit between the field's options & the container's defaults
+    let container_defaults = when_rules
+        .iter()
+        .rev()
+        .filter(|rule| types::matches_pattern(&rule.ty, &ty))
+        .fold(container_defaults, |acc, rule| {
+            Cow::Owned(rule.defaults.or(&acc))
+        });
+
+    // Container-level `map` skips fields it can't rebuild the struct around
+    let map_supported = access.variants.is_none() && !access.union;
+
+    let (get_defaults, get_naming) = if is_bool {
+        (
+            Cow::Owned(container_defaults.get_bool.or(&container_defaults.get)),
+            &Naming::GET_BOOL,
+        )
+    } else {
+        (Cow::Borrowed(&container_defaults.get), &Naming::GET)
+    };
+
+    variations!(
+        [FinalOptions, container_opts, container_defaults, Naming, opts, tokens, name_base, struct_name, comments] =>
+        [get GET #[must_use] unsafe(safety) defaults(&get_defaults) naming(get_naming) => RENDER_GET(&access, &ty, generics)],
+        [get_mut GET_MUT #[must_use] unsafe(safety) => RENDER_GET_MUT(&access, &ty)]
+    );
+
+    if is_bool {
+        variations!(
+            [FinalOptions, container_opts, container_defaults, Naming, opts, tokens, name_base, struct_name, comments] =>
+            [toggle TOGGLE unsafe(safety) => RENDER_TOGGLE(&access)],
+            [enable ENABLE => RENDER_ENABLE(&access)],
+            [disable DISABLE => RENDER_DISABLE(&access)]
+        );
+    }
+
+    variations!(
+        [FinalOptions, container_opts, container_defaults, Naming, opts, tokens, name_base, struct_name, comments] =>
+        [set SET => RENDER_SET(&access, &ty, struct_shape)],
+        [with WITH => RENDER_WITH(&access, &ty, struct_shape)],
+        [replace REPLACE unsafe(safety) const(false) => RENDER_REPLACE(&access, &ty)],
+        [take TAKE unsafe(safety) const(false) enable(container_opts.take || is_option) => RENDER_TAKE(&access, &ty)],
+        [swap SWAP unsafe(safety) const(false) => RENDER_SWAP(&access, &ty)],
+        [update UPDATE unsafe(safety) const(false) => RENDER_UPDATE(&access, &ty)],
+        [map MAP const(false) enable(container_opts.map && map_supported) => RENDER_MAP(&access, &ty)],
+        [is_some IS_SOME #[must_use] unsafe(safety) enable(is_option) => RENDER_IS_SOME(&access, &ty)],
+        [clear CLEAR const(false) enable(is_option) => RENDER_CLEAR(&access, &ty)],
+        [or OR #[must_use] unsafe(safety) const(false) enable(is_option) => RENDER_OR(&access, &ty)],
+        [or_insert_with OR_INSERT_WITH unsafe(safety) const(false) enable(is_option) => RENDER_OR_INSERT_WITH(&access, &ty)]
+    );
+}
+
+fn render_variant(
+    tokens: &mut TokenStream,
+    variant: ParsedVariant,
+    container_opts: &ContainerOptions,
+    struct_name: &str,
+    catch_all: bool,
+) {
+    let name_base = variant.name_base();
+    let ParsedVariant {
+        comments,
+        opts,
+        shape,
+    } = variant;
+
+    if opts.skip {
+        return;
+    }
+
+    let container_defaults = &container_opts.defaults;
+    variations!(
+        [FinalOptions, container_opts, container_defaults, Naming, opts, tokens, name_base, struct_name, comments] =>
+        [is_variant IS_VARIANT #[must_use] => RENDER_IS_VARIANT(&shape, catch_all)]
+    );
+
+    if shape.fields.is_empty() {
+        return;
+    }
+
+    variations!(
+        [FinalOptions, container_opts, container_defaults, Naming, opts, tokens, name_base, struct_name, comments] =>
+        [as_variant AS_VARIANT #[must_use] => RENDER_AS_VARIANT(&shape, catch_all)],
+        [as_variant_mut AS_VARIANT_MUT #[must_use] => RENDER_AS_VARIANT_MUT(&shape, catch_all)],
+        [into_variant INTO_VARIANT #[must_use] => RENDER_INTO_VARIANT(&shape, catch_all)]
+    );
+}
+
 #[allow(clippy::needless_pass_by_value)]
 fn render_common(
     tokens: &mut TokenStream,
-    ident: &Ident,
     comments: &[Attribute],
     opts: &FinalOptions,
-    must_use: Option<Attribute>,
+    attr: Option<Attribute>,
 ) {
     let vis = &opts.vis;
+    let safety = opts.safety.as_ref().map(move |doc| {
+        let spacer = if comments.is_empty() {
+            None
+        } else {
+            Some(quote!(#[doc = ""]))
+        };
+
+        quote! {
+            #spacer
+            #[doc = " # Safety"]
+            #[doc = ""]
+            #[doc = #doc]
+        }
+    });
+
     tokens.extend(quote! {
         #(#comments)*
+        #safety
         #[inline]
-        #must_use
+        #attr
         #vis
     });
 
     if opts.const_fn {
         tokens.append(Ident::create("const"));
     }
+    if opts.safety.is_some() {
+        tokens.append(Ident::create("unsafe"));
+    }
 
     tokens.append(Ident::create("fn"));
+}
 
-    tokens.append(match (&opts.prefix, &opts.suffix) {
-        (Some(p), Some(s)) => format_ident!("{}{ident}{}", p.as_prefix(), s.as_suffix()),
-        (Some(p), None) => format_ident!("{}{ident}", p.as_prefix()),
-        (None, Some(s)) => format_ident!("{ident}{}", s.as_suffix()),
-        (None, None) => ident.clone(),
-    });
+fn accessor_name(name_base: &Member, struct_name: &str, opts: &FinalOptions) -> syn::Result<Ident> {
+    if let Some(ref name) = opts.name {
+        return Ok(name.clone());
+    }
+
+    // Raw identifiers get stripped here & re-rawed below if the final name is still a keyword
+    let base = match name_base {
+        Member::Named(ident) => ident.unraw().to_string(),
+        Member::Unnamed(idx) => idx.index.to_string(),
+    };
+
+    // `bool` fields named like predicates already read as one: `is_ready()`, not `is_is_ready()`
+    let prefix = match opts.prefix {
+        Some(SkippableIdent::Ident(ref p))
+            if opts.bool_getter
+                && p == "is"
+                && (base.starts_with("is_") || base.starts_with("has_")) =>
+        {
+            &None
+        }
+        ref prefix => prefix,
+    };
+
+    let mut name = match (&opts.name_template, prefix, &opts.suffix) {
+        (Some(template), _, _) => template.render(&base, opts.kind, struct_name),
+        (None, Some(p), Some(s)) => format!("{}{base}{}", p.as_prefix(), s.as_suffix()),
+        (None, Some(p), None) => format!("{}{base}", p.as_prefix()),
+        (None, None, Some(s)) => format!("{base}{}", s.as_suffix()),
+        (None, None, None) => base,
+    };
+
+    // Tuple struct fields without a `name` option get named after their index
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        name.insert(0, '_');
+    }
+
+    if is_ident(&name) {
+        Ok(to_ident(&name, name_base.span()))
+    } else {
+        let span = opts
+            .name_template
+            .as_ref()
+            .map_or_else(move || name_base.span(), NameTemplate::span);
+        let msg = format!("The accessor name `{name}` isn't a valid identifier");
+        Err(syn::Error::new(span, msg))
+    }
+}
+
+/// Render the headers of the deprecated aliases up to & including their names
+fn alias_headers(
+    name: &Ident,
+    opts: &FinalOptions,
+    attr: Option<&Attribute>,
+) -> Vec<(Ident, TokenStream)> {
+    let doc = format!(" Deprecated alias of [`{name}`](Self::{name})");
+    let note = format!("use `{name}`");
+    let attrs: [Attribute; 2] = [
+        syn::parse_quote!(#[doc = #doc]),
+        syn::parse_quote!(#[deprecated(note = #note)]),
+    ];
+
+    opts.aliases
+        .iter()
+        .map(move |alias| {
+            let mut tokens = TokenStream::new();
+            render_common(&mut tokens, &attrs, opts, attr.cloned());
+            tokens.append(alias.clone());
+
+            (alias.clone(), tokens)
+        })
+        .collect()
+}
+
+/// Render aliases forwarding to the accessor rendered by `rest`
+fn render_aliases(
+    name: &Ident,
+    aliases: Vec<(Ident, TokenStream)>,
+    rest: &TokenStream,
+) -> TokenStream {
+    let mut tokens = TokenStream::new();
+    if aliases.is_empty() {
+        return tokens;
+    }
+
+    let ItemFn { sig, .. } = syn::parse_quote!(fn #name #rest);
+    let is_unsafe = sig.unsafety.is_some();
+    let Signature {
+        generics,
+        mut inputs,
+        output,
+        ..
+    } = sig;
+
+    let mut args = Vec::with_capacity(inputs.len());
+    for input in &mut inputs {
+        match input {
+            FnArg::Receiver(receiver) => {
+                if receiver.reference.is_none() {
+                    receiver.mutability = None;
+                }
+                args.push(quote!(self));
+            }
+            FnArg::Typed(PatType { pat, .. }) => {
+                if let Pat::Ident(pat) = &mut **pat {
+                    pat.mutability = None;
+                }
+                args.push(pat.to_token_stream());
+            }
+        }
+    }
+
+    let (_, _, where_clause) = generics.split_for_impl();
+    let params = &generics.params;
+    let params = if params.is_empty() {
+        None
+    } else {
+        Some(quote!(<#params>))
+    };
+    let mut body = quote!(Self::#name(#(#args),*));
+    if is_unsafe {
+        body = quote!(unsafe { #body });
+    }
+
+    for (_, mut alias) in aliases {
+        alias.extend(quote!(#params (#inputs) #output #where_clause { #body }));
+        tokens.extend(alias);
+    }
+
+    tokens
 }
 
 fn arg_ref(owned: bool) -> Option<Token![&]> {
@@ -191,10 +518,21 @@ fn resolve_ptr_ty(base: &Type, opt: Option<DerefKind>) -> &Type {
     }
 }
 
-type RenderFieldFn = fn(&Ident, &Type, FinalOptions) -> TokenStream;
-type LastRenderFieldFn = fn(Ident, Type, FinalOptions) -> TokenStream;
+type RenderFieldFn = fn(&FieldAccess, &Type, FinalOptions) -> TokenStream;
+
+type RenderGetFn = fn(&FieldAccess, &Type, &Generics, FinalOptions) -> TokenStream;
+
+const RENDER_GET: RenderGetFn = |access, ty, generics, mut opts| {
+    if let Some(default) = opts.default.take() {
+        return render_get_default(access, ty, generics, opts, default);
+    }
+    if opts.deref || opts.as_deref {
+        return render_get_deref(access, ty, opts, false);
+    }
+    if let Err(e) = check_conversion(ty, &opts) {
+        return e;
+    }
 
-const RENDER_GET: RenderFieldFn = |ident, ty, opts| {
     let arg_ref = arg_ref(opts.owned);
 
     let val_ref = if opts.cp || opts.owned || opts.as_ref {
@@ -206,78 +544,1005 @@ const RENDER_GET: RenderFieldFn = |ident, ty, opts| {
         Some(quote!(&))
     };
 
-    let fn_return = if let Some(ty) = opts.ty {
+    let option_ref = if opts.as_ref && opts.ptr_deref.is_none() {
+        types::option_inner(ty)
+    } else {
+        None
+    };
+    let converted = opts.conv.is_some();
+    let mut fn_return = if let Some(ty) = opts.ty {
         ty.into_token_stream()
+    } else if let Some(inner) = option_ref {
+        if converted {
+            quote!(::core::option::Option<#inner>)
+        } else {
+            quote!(::core::option::Option<&#inner>)
+        }
     } else {
         let ty = resolve_ptr_ty(ty, opts.ptr_deref);
-        quote!(#val_ref #ty)
+        if converted {
+            ty.into_token_stream()
+        } else {
+            quote!(#val_ref #ty)
+        }
     };
+    if !access.exhaustive {
+        fn_return = quote!(::core::option::Option<#fn_return>);
+    }
 
     let where_clause = mk_where(opts.bounds);
+    let (conv, map) = (opts.conv, opts.map);
 
-    let body = if opts.ptr_deref.is_some() {
-        quote!(unsafe { #val_ref *self.#ident })
-    } else if opts.as_ref {
-        quote!(self.#ident.as_ref())
+    let receiver = if opts.owned {
+        Receiver::Owned
     } else {
-        quote!(#val_ref self.#ident)
+        Receiver::Ref
     };
+    let body = access.render(
+        receiver,
+        move |place| {
+            let value = if opts.ptr_deref.is_some() {
+                quote!(unsafe { #val_ref *#place })
+            } else if opts.as_ref {
+                quote!(#place.as_ref())
+            } else {
+                quote!(#val_ref #place)
+            };
+            let value = wrap_union(access, opts.ptr_deref, value);
+
+            wrap_some(
+                access,
+                convert_value(value, conv, map.as_ref(), option_ref.is_some()),
+            )
+        },
+        quote!(::core::option::Option::None),
+    );
 
     quote!((#arg_ref self) -> #fn_return #where_clause { #body })
 };
 
-const RENDER_GET_MUT: RenderFieldFn = |ident, ty, opts| {
-    let fn_return = if let Some(ty) = opts.ty {
+/// Reject `conv` & `map` combinations a getter's return type can't be inferred for
+fn check_conversion(ty: &Type, opts: &FinalOptions) -> Result<(), TokenStream> {
+    let msg = match opts.conv {
+        Some(Conversion::Clone | Conversion::Copied) if opts.cp || opts.owned => {
+            "`conv = clone` & `conv = copied` can't be combined with `cp` or `owned`"
+        }
+        _ if opts.ty.is_some() => return Ok(()),
+        Some(Conversion::Into | Conversion::ToOwned) => {
+            "`conv = into` & `conv = to_owned` require the return type to be set with `ty`"
+        }
+        _ if opts.map.is_some() => "`map` requires the return type to be set with `ty`",
+        _ => return Ok(()),
+    };
+
+    let e = syn::Error::new_spanned(ty, msg).into_compile_error();
+    Err(quote!(() { #e }))
+}
+
+/// Error out of getters that `conv` & `map` don't apply to
+fn reject_conversion(ty: &Type, opts: &FinalOptions, option: &str) -> Option<TokenStream> {
+    if opts.conv.is_none() && opts.map.is_none() {
+        return None;
+    }
+
+    let msg = format!("`conv` & `map` can't be combined with {option}");
+    let e = syn::Error::new_spanned(ty, msg).into_compile_error();
+    Some(quote!(() { #e }))
+}
+
+/// Apply a getter's `conv` & `map` to its value
+fn convert_value(
+    value: TokenStream,
+    conv: Option<Conversion>,
+    map: Option<&syn::Path>,
+    option_ref: bool,
+) -> TokenStream {
+    let value = match conv {
+        None => value,
+        Some(Conversion::Clone) if option_ref => quote!((#value).cloned()),
+        Some(Conversion::Clone) => quote!(::core::clone::Clone::clone(#value)),
+        Some(Conversion::Copied) if option_ref => quote!((#value).copied()),
+        Some(Conversion::Copied) => quote!(*#value),
+        Some(Conversion::Into) => quote!(::core::convert::Into::into(#value)),
+        Some(Conversion::ToOwned) => quote!((#value).to_owned()),
+    };
+
+    match map {
+        Some(map) => quote!(#map(#value)),
+        None => value,
+    }
+}
+
+/// Render a getter going through the field's [`Deref`](core::ops::Deref) or
+/// [`DerefMut`](core::ops::DerefMut) impl
+fn render_get_deref(
+    access: &FieldAccess,
+    ty: &Type,
+    opts: FinalOptions,
+    mutable: bool,
+) -> TokenStream {
+    if opts.owned {
+        let e = syn::Error::new_spanned(ty, "`deref` & `as_deref` can't be combined with `owned`")
+            .into_compile_error();
+        return quote!(() { #e });
+    }
+    if let Some(e) = reject_conversion(ty, &opts, "`deref` or `as_deref`") {
+        return e;
+    }
+
+    let field_ty = resolve_ptr_ty(ty, opts.ptr_deref);
+    let target_of = if opts.as_deref {
+        match types::option_inner(field_ty) {
+            Some(inner) => inner,
+            None => return option_error(field_ty, "as_deref"),
+        }
+    } else {
+        field_ty
+    };
+    let mut_token = if mutable {
+        Some(<Token![mut]>::default())
+    } else {
+        None
+    };
+
+    let mut fn_return = if let Some(ty) = opts.ty {
+        ty.into_token_stream()
+    } else {
+        let target = quote!(<#target_of as ::core::ops::Deref>::Target);
+        if opts.as_deref {
+            quote!(::core::option::Option<&#mut_token #target>)
+        } else {
+            quote!(&#mut_token #target)
+        }
+    };
+    if !access.exhaustive {
+        fn_return = quote!(::core::option::Option<#fn_return>);
+    }
+
+    let where_clause = mk_where(opts.bounds);
+    let ptr_deref = opts.ptr_deref.is_some();
+    let as_deref = opts.as_deref;
+    let body = access.render(
+        Receiver::Ref,
+        move |place| {
+            let place = option_place(place, ptr_deref);
+            let value = match (as_deref, mutable) {
+                (true, true) => quote!(#place.as_deref_mut()),
+                (true, false) => quote!(#place.as_deref()),
+                (false, _) => quote!(&#mut_token *#place),
+            };
+            wrap_some(access, wrap_unsafe_read(access, ptr_deref, value))
+        },
+        quote!(::core::option::Option::None),
+    );
+
+    quote!((&#mut_token self) -> #fn_return #where_clause { #body })
+}
+
+/// Render a getter of an `Option` field falling back to a default value
+fn render_get_default(
+    access: &FieldAccess,
+    ty: &Type,
+    generics: &Generics,
+    mut opts: FinalOptions,
+    default: DefaultValue,
+) -> TokenStream {
+    if let Some(e) = reject_conversion(ty, &opts, "`default`") {
+        return e;
+    }
+    let field_ty = resolve_ptr_ty(ty, opts.ptr_deref);
+    let Some(inner) = types::option_inner(field_ty) else {
+        return option_error(field_ty, "default");
+    };
+
+    let by_value = opts.cp || opts.owned;
+    let fallback = match default {
+        DefaultValue::Expr(expr) if by_value => expr.into_token_stream(),
+        // The `const` item below can't use the struct's generics
+        DefaultValue::Expr(_) if types::uses_generics(inner, generics) => {
+            let msg = "`default = expr` can't return a reference to a type using the struct's generic parameters; add `cp` or `owned`";
+            let e = syn::Error::new_spanned(field_ty, msg).into_compile_error();
+            return quote!(() { #e });
+        }
+        DefaultValue::Expr(expr) => quote! {{
+            const DEFAULT: &#inner = &(#expr);
+            DEFAULT
+        }},
+        DefaultValue::Default if by_value => {
+            opts.bounds
+                .push(syn::parse_quote!(#inner: ::core::default::Default));
+            quote!(::core::default::Default::default())
+        }
+        DefaultValue::Default => {
+            let msg = "`default` without a value requires `cp` or `owned`; use `default = expr` to return a reference";
+            let e = syn::Error::new_spanned(field_ty, msg).into_compile_error();
+            return quote!(() { #e });
+        }
+    };
+
+    let arg_ref = arg_ref(opts.owned);
+    let mut fn_return = if let Some(ty) = opts.ty {
+        ty.into_token_stream()
+    } else if by_value {
+        inner.into_token_stream()
+    } else {
+        quote!(&#inner)
+    };
+    if !access.exhaustive {
+        fn_return = quote!(::core::option::Option<#fn_return>);
+    }
+
+    let where_clause = mk_where(opts.bounds);
+    let ptr_deref = opts.ptr_deref.is_some();
+    let receiver = if opts.owned && !ptr_deref {
+        Receiver::Owned
+    } else {
+        Receiver::Ref
+    };
+    let val_ref = if by_value { None } else { Some(quote!(&)) };
+    let body = access.render(
+        receiver,
+        move |place| {
+            let place = option_place(place, ptr_deref);
+            let value = wrap_unsafe_read(access, ptr_deref, quote!(#val_ref #place));
+            wrap_some(
+                access,
+                quote! {
+                    match #value {
+                        ::core::option::Option::Some(value) => value,
+                        ::core::option::Option::None => #fallback,
+                    }
+                },
+            )
+        },
+        quote!(::core::option::Option::None),
+    );
+
+    quote!((#arg_ref self) -> #fn_return #where_clause { #body })
+}
+
+const RENDER_GET_MUT: RenderFieldFn = |access, ty, opts| {
+    if opts.deref || opts.as_deref {
+        return render_get_deref(access, ty, opts, true);
+    }
+
+    let option_ref = if opts.as_ref && opts.ptr_deref.is_none() {
+        types::option_inner(ty)
+    } else {
+        None
+    };
+    let mut fn_return = if let Some(ty) = opts.ty {
         ty.into_token_stream()
+    } else if let Some(inner) = option_ref {
+        quote!(::core::option::Option<&mut #inner>)
     } else {
         let ty = resolve_ptr_ty(ty, opts.ptr_deref);
         quote!(&mut #ty)
     };
+    if !access.exhaustive {
+        fn_return = quote!(::core::option::Option<#fn_return>);
+    }
 
     let where_clause = mk_where(opts.bounds);
 
-    let body = if opts.as_ref {
-        quote!(self.#ident.as_mut())
-    } else if opts.ptr_deref.is_some() {
-        quote!(unsafe { &mut *self.#ident })
+    let body = access.render(
+        Receiver::Ref,
+        move |place| {
+            let value = if opts.as_ref {
+                quote!(#place.as_mut())
+            } else if opts.ptr_deref.is_some() {
+                quote!(unsafe { &mut *#place })
+            } else {
+                quote!(&mut #place)
+            };
+
+            wrap_some(access, wrap_union(access, opts.ptr_deref, value))
+        },
+        quote!(::core::option::Option::None),
+    );
+
+    quote!((&mut self) -> #fn_return #where_clause { #body })
+};
+
+type RenderSetterFn = fn(&FieldAccess, &Type, Option<&StructShape>, FinalOptions) -> TokenStream;
+
+const RENDER_SET: RenderSetterFn = |access, ty, struct_shape, opts| {
+    if opts.retype {
+        return render_retype(access, ty, struct_shape, opts);
+    }
+
+    let returns = match opts.returns {
+        Some(returns) => {
+            if let Err(e) = returns.check(opts.owned, access.union) {
+                return quote!(() { #e });
+            }
+            returns.style
+        }
+        None if opts.owned => ReturnStyle::Owned,
+        None => ReturnStyle::Ref,
+    };
+
+    render_setter(access, ty, opts, returns)
+};
+
+const RENDER_WITH: RenderSetterFn = |access, ty, struct_shape, mut opts| {
+    opts.owned = true;
+    opts.returns = None;
+    RENDER_SET(access, ty, struct_shape, opts)
+};
+
+const RENDER_REPLACE: RenderFieldFn = |access, ty, mut opts| {
+    opts.owned = false;
+    render_setter(access, ty, opts, ReturnStyle::Old)
+};
+
+const RENDER_TAKE: RenderFieldFn = |access, ty, mut opts| {
+    if let Some(ty) = opts.ty {
+        let msg = "`take` returns the field's value as-is, so `ty` can't be set on it";
+        let e = syn::Error::new_spanned(ty, msg).into_compile_error();
+        return quote!(() { #e });
+    }
+
+    let field_ty = resolve_ptr_ty(ty, opts.ptr_deref);
+    let fn_return = if access.exhaustive {
+        field_ty.into_token_stream()
     } else {
-        quote!(&mut self.#ident)
+        quote!(::core::option::Option<#field_ty>)
     };
 
+    opts.bounds
+        .push(syn::parse_quote!(#field_ty: ::core::default::Default));
+    let where_clause = mk_where(opts.bounds);
+
+    let ptr_deref = opts.ptr_deref.is_some();
+    let body = access.render(
+        Receiver::Ref,
+        move |place| {
+            let value = if ptr_deref {
+                quote!(unsafe { ::core::mem::take(&mut *#place) })
+            } else {
+                wrap_union(access, None, quote!(::core::mem::take(&mut #place)))
+            };
+            wrap_some(access, value)
+        },
+        quote!(::core::option::Option::None),
+    );
+
     quote!((&mut self) -> #fn_return #where_clause { #body })
 };
 
-const RENDER_SET: LastRenderFieldFn = |ident, ty, opts| {
+const RENDER_SWAP: RenderFieldFn = |access, _, opts| {
+    let where_clause = mk_where(opts.bounds);
+    let other = Ident::create("other");
+
+    let ptr_deref = opts.ptr_deref.is_some();
+    let swap = access.render(
+        Receiver::Ref,
+        move |place| {
+            access.render_on(
+                &other,
+                Receiver::Ref,
+                move |other_place| {
+                    let swap = if ptr_deref {
+                        quote!(unsafe { ::core::mem::swap(&mut *#place, &mut *#other_place) })
+                    } else {
+                        let swap = quote!(::core::mem::swap(&mut #place, &mut #other_place));
+                        wrap_union(access, None, swap)
+                    };
+                    quote!({ #swap; })
+                },
+                quote!(return ::core::option::Option::None),
+            )
+        },
+        quote!(return ::core::option::Option::None),
+    );
+
+    if access.exhaustive {
+        quote! {
+            (&mut self, other: &mut Self) -> &mut Self #where_clause {
+                #swap
+                self
+            }
+        }
+    } else {
+        quote! {
+            (&mut self, other: &mut Self) -> ::core::option::Option<&mut Self> #where_clause {
+                #swap;
+                ::core::option::Option::Some(self)
+            }
+        }
+    }
+};
+
+const RENDER_UPDATE: RenderFieldFn = |access, ty, opts| {
+    let arg_ty = if let Some(ref ty) = opts.ty {
+        ty
+    } else {
+        resolve_ptr_ty(ty, opts.ptr_deref)
+    };
+    let params = quote!(f: impl ::core::ops::FnOnce(&mut #arg_ty));
+
+    let ptr_deref = opts.ptr_deref.is_some();
+    render_in_place(access, opts, Some(params), move |place| {
+        let value = if ptr_deref {
+            quote!(unsafe { &mut *#place })
+        } else {
+            wrap_union(access, None, quote!(&mut #place))
+        };
+        quote!(f(#value);)
+    })
+};
+
+const RENDER_MAP: RenderFieldFn = |access, ty, opts| {
+    if access.variants.is_some() || access.union || opts.ptr_deref.is_some() {
+        let e = syn::Error::new_spanned(
+            &access.member,
+            "`map` is only supported on struct fields without `ptr_deref`",
+        )
+        .into_compile_error();
+        return quote!(() { #e });
+    }
+
+    let member = &access.member;
+    let where_clause = mk_where(opts.bounds);
+
+    quote! {
+        (mut self, f: impl ::core::ops::FnOnce(#ty) -> #ty) -> Self #where_clause {
+            self.#member = f(self.#member);
+            self
+        }
+    }
+};
+
+/// Render a setter rebuilding the struct with the field's type parameter changed
+fn render_retype(
+    access: &FieldAccess,
+    ty: &Type,
+    struct_shape: Option<&StructShape>,
+    opts: FinalOptions,
+) -> TokenStream {
+    let retyped = match struct_shape {
+        Some(shape) => shape.retype(&access.member, ty),
+        None => Err(syn::Error::new_spanned(
+            &access.member,
+            "`retype` is only supported on structs",
+        )),
+    };
+    let Retyped {
+        ident,
+        param,
+        predicates,
+        output,
+        value,
+    } = match retyped {
+        Ok(retyped) => retyped,
+        Err(e) => {
+            let e = e.into_compile_error();
+            return quote!(() { #e });
+        }
+    };
+
+    let mut predicates = predicates;
+    predicates.extend(opts.bounds.into_iter().map(ToTokens::into_token_stream));
+    let where_clause = if predicates.is_empty() {
+        None
+    } else {
+        Some(quote!(where #(#predicates),*))
+    };
+
+    quote! {
+        <#param>(self, new_value: #ident) -> #output #where_clause {
+            #value
+        }
+    }
+}
+
+/// Render the statement converting a setter's argument with `from` or `try_from`, along with the
+/// error type of `try_from`, which hands `self` back on owned receivers
+fn render_from(
+    access: &FieldAccess,
+    ty: &Type,
+    stored_ty: &Type,
+    arg_ty: &Type,
+    owned: bool,
+    (from, try_from, try_error): (Option<Converter>, Option<Converter>, Option<Type>),
+) -> Result<Option<(TokenStream, Option<TokenStream>)>, TokenStream> {
+    let error = move |msg: &str| {
+        let e = syn::Error::new_spanned(ty, msg).into_compile_error();
+        quote!(() { #e })
+    };
+
+    if let (None, Some(try_error)) = (&try_from, &try_error) {
+        let msg = "`error` only applies to `try_from`";
+        let e = syn::Error::new_spanned(try_error, msg).into_compile_error();
+        return Err(quote!(() { #e }));
+    }
+
+    match (from, try_from) {
+        (Some(_), Some(_)) => Err(error("`from` & `try_from` can't be combined")),
+        (Some(from), None) => {
+            let call = match from {
+                Converter::Trait => quote!(::core::convert::From::from),
+                Converter::Fn(path) => path.into_token_stream(),
+            };
+            Ok(Some((
+                quote!(let new_value: #stored_ty = #call(new_value);),
+                None,
+            )))
+        }
+        (None, Some(_)) if !access.exhaustive => Err(error(
+            "`try_from` isn't supported on fields missing from some variants",
+        )),
+        (None, Some(try_from)) => {
+            let (call, try_error) = match (try_from, try_error) {
+                (Converter::Trait, try_error) => (
+                    quote!(::core::convert::TryFrom::try_from),
+                    try_error.map_or_else(
+                        || quote!(<#stored_ty as ::core::convert::TryFrom<#arg_ty>>::Error),
+                        ToTokens::into_token_stream,
+                    ),
+                ),
+                (Converter::Fn(path), Some(try_error)) => {
+                    (path.into_token_stream(), try_error.into_token_stream())
+                }
+                (Converter::Fn(_), None) => {
+                    return Err(error(
+                        "`try_from = path` requires the error type to be set with `error`",
+                    ))
+                }
+            };
+            if owned {
+                let statement = quote! {
+                    let new_value: #stored_ty = match #call(new_value) {
+                        ::core::result::Result::Ok(new_value) => new_value,
+                        ::core::result::Result::Err(e) => {
+                            return ::core::result::Result::Err((self, e));
+                        }
+                    };
+                };
+                Ok(Some((statement, Some(quote!((Self, #try_error))))))
+            } else {
+                Ok(Some((
+                    quote!(let new_value: #stored_ty = #call(new_value)?;),
+                    Some(try_error),
+                )))
+            }
+        }
+        (None, None) => Ok(None),
+    }
+}
+
+fn render_setter(
+    access: &FieldAccess,
+    ty: &Type,
+    mut opts: FinalOptions,
+    returns: ReturnStyle,
+) -> TokenStream {
     let arg_ref = arg_ref(opts.owned);
 
-    let self_ref = if opts.cp || opts.owned {
+    let field_ty = resolve_ptr_ty(ty, opts.ptr_deref);
+    let some_inner = if opts.some {
+        match types::option_inner(field_ty) {
+            Some(inner) => Some(inner),
+            None => return option_error(field_ty, "some"),
+        }
+    } else {
         None
+    };
+    let stored_ty = some_inner.unwrap_or(field_ty);
+    let arg_ty = opts.ty.as_ref().unwrap_or(stored_ty);
+    let stored = if opts.some {
+        quote!(::core::option::Option::Some(new_value))
     } else {
-        Some(quote!(&mut))
+        quote!(new_value)
     };
 
-    let arg_ty = if let Some(ref ty) = opts.ty {
-        ty
+    let (param_ty, mut conversion) = if opts.into {
+        (
+            quote!(impl ::core::convert::Into<#arg_ty>),
+            Some(quote!(let new_value: #arg_ty = ::core::convert::Into::into(new_value);)),
+        )
     } else {
-        resolve_ptr_ty(&ty, opts.ptr_deref)
+        (arg_ty.to_token_stream(), None)
     };
 
+    // `new_value`'s type after `from`/`try_from` & the error type of `try_from`
+    let converters = (opts.from.take(), opts.try_from.take(), opts.error.take());
+    let (value_ty, try_error) =
+        match render_from(access, ty, stored_ty, arg_ty, opts.owned, converters) {
+            Ok(Some((statement, try_error))) => {
+                conversion
+                    .get_or_insert_with(TokenStream::new)
+                    .extend(statement);
+                (stored_ty, try_error)
+            }
+            Ok(None) => (arg_ty, None),
+            Err(e) => return e,
+        };
+
     let where_clause = mk_where(opts.bounds);
 
-    let assignment = if opts.ptr_deref.is_some() {
-        quote! { unsafe { *self.#ident = new_value; } }
+    let (receiver, rejection) = if opts.owned {
+        (Receiver::OwnedMut, quote!((self, new_value)))
     } else {
-        quote! { self.#ident = new_value }
+        (Receiver::Ref, quote!(new_value))
+    };
+    let ptr_deref = opts.ptr_deref.is_some();
+    let assignment = access.render(
+        receiver,
+        move |place| match (returns, ptr_deref) {
+            (ReturnStyle::Old, true) => {
+                quote! { unsafe { ::core::mem::replace(&mut *#place, #stored) } }
+            }
+            (ReturnStyle::Old, false) => {
+                let replace = quote! { ::core::mem::replace(&mut #place, #stored) };
+                wrap_union(access, None, replace)
+            }
+            (_, true) => quote! { unsafe { *#place = #stored; } },
+            (_, false) => quote! { #place = #stored },
+        },
+        quote!(return ::core::result::Result::Err(#rejection)),
+    );
+
+    let (ok_ty, ok_value) = match returns {
+        ReturnStyle::Ref => (quote!(&mut Self), quote!(self)),
+        ReturnStyle::Owned => (quote!(Self), quote!(self)),
+        ReturnStyle::Unit => (quote!(()), quote!(())),
+        ReturnStyle::Old if opts.some => (field_ty.to_token_stream(), TokenStream::new()),
+        ReturnStyle::Old => (stored_ty.to_token_stream(), TokenStream::new()),
+    };
+    let fallible = !access.exhaustive || try_error.is_some();
+    let body = match (returns, fallible) {
+        (ReturnStyle::Old, false) => assignment,
+        (ReturnStyle::Old, true) => quote!(::core::result::Result::Ok(#assignment)),
+        (ReturnStyle::Unit, false) => quote!(#assignment;),
+        (_, false) => quote!(#assignment; #ok_value),
+        (_, true) => quote!(#assignment; ::core::result::Result::Ok(#ok_value)),
+    };
+
+    let fn_return = if let Some(error) = try_error {
+        quote!(::core::result::Result<#ok_ty, #error>)
+    } else if access.exhaustive {
+        ok_ty
+    } else {
+        let err_ty = if opts.owned {
+            quote!((Self, #value_ty))
+        } else {
+            value_ty.into_token_stream()
+        };
+        quote!(::core::result::Result<#ok_ty, #err_ty>)
     };
 
     quote! {
-        (#arg_ref mut self, new_value: #arg_ty) -> #self_ref Self #where_clause {
-            #assignment;
-            self
+        (#arg_ref mut self, new_value: #param_ty) -> #fn_return #where_clause {
+            #conversion
+            #body
         }
     }
+}
+
+/// Error out of an `Option`-only accessor used on a field of another type
+fn option_error(ty: &Type, option: &str) -> TokenStream {
+    let msg = format!("`{option}` requires an `Option` field");
+    let e = syn::Error::new_spanned(ty, msg).into_compile_error();
+    quote!(() { #e })
+}
+
+/// The field's `Option` place, dereferencing raw pointers
+fn option_place(place: TokenStream, ptr_deref: bool) -> TokenStream {
+    if ptr_deref {
+        quote!((*#place))
+    } else {
+        place
+    }
+}
+
+/// Wrap a read of the field in `unsafe` for raw pointers & unions
+fn wrap_unsafe_read(access: &FieldAccess, ptr_deref: bool, value: TokenStream) -> TokenStream {
+    if ptr_deref {
+        quote!(unsafe { #value })
+    } else {
+        wrap_union(access, None, value)
+    }
+}
+
+const RENDER_IS_SOME: RenderFieldFn = |access, ty, opts| {
+    let field_ty = resolve_ptr_ty(ty, opts.ptr_deref);
+    if types::option_inner(field_ty).is_none() {
+        return option_error(field_ty, "is_some");
+    }
+
+    let where_clause = mk_where(opts.bounds);
+    let ptr_deref = opts.ptr_deref.is_some();
+    let body = access.render(
+        Receiver::Ref,
+        move |place| {
+            let place = option_place(place, ptr_deref);
+            wrap_unsafe_read(access, ptr_deref, quote!(#place.is_some()))
+        },
+        quote!(false),
+    );
+
+    quote!((&self) -> bool #where_clause { #body })
 };
 
+const RENDER_CLEAR: RenderFieldFn = |access, ty, opts| {
+    let field_ty = resolve_ptr_ty(ty, opts.ptr_deref);
+    if types::option_inner(field_ty).is_none() {
+        return option_error(field_ty, "clear");
+    }
+
+    let ptr_deref = opts.ptr_deref.is_some();
+    render_in_place(access, opts, None, move |place| {
+        if ptr_deref {
+            quote! { unsafe { *#place = ::core::option::Option::None; } }
+        } else {
+            quote! { #place = ::core::option::Option::None; }
+        }
+    })
+};
+
+const RENDER_OR: RenderFieldFn = |access, ty, opts| {
+    let field_ty = resolve_ptr_ty(ty, opts.ptr_deref);
+    let Some(inner) = types::option_inner(field_ty) else {
+        return option_error(field_ty, "or");
+    };
+
+    let ptr_deref = opts.ptr_deref.is_some();
+    let by_value = opts.cp || opts.owned;
+    let (generics, params, fn_return) = match (opts.owned, by_value) {
+        (true, _) => (None, quote!(self, default: #inner), quote!(#inner)),
+        (false, true) => (None, quote!(&self, default: #inner), quote!(#inner)),
+        (false, false) => (
+            Some(quote!(<'__accessory>)),
+            quote!(&'__accessory self, default: &'__accessory #inner),
+            quote!(&'__accessory #inner),
+        ),
+    };
+    let receiver = if opts.owned && !ptr_deref {
+        Receiver::Owned
+    } else {
+        Receiver::Ref
+    };
+
+    let where_clause = mk_where(opts.bounds);
+    let body = access.render(
+        receiver,
+        move |place| {
+            let place = option_place(place, ptr_deref);
+            let value = if by_value {
+                quote!(#place.unwrap_or(default))
+            } else {
+                quote!(#place.as_ref().unwrap_or(default))
+            };
+            wrap_unsafe_read(access, ptr_deref, value)
+        },
+        quote!(default),
+    );
+
+    quote!(#generics(#params) -> #fn_return #where_clause { #body })
+};
+
+const RENDER_OR_INSERT_WITH: RenderFieldFn = |access, ty, opts| {
+    let field_ty = resolve_ptr_ty(ty, opts.ptr_deref);
+    let Some(inner) = types::option_inner(field_ty) else {
+        return option_error(field_ty, "or_insert_with");
+    };
+
+    let mut fn_return = quote!(&mut #inner);
+    if !access.exhaustive {
+        fn_return = quote!(::core::option::Option<#fn_return>);
+    }
+
+    let where_clause = mk_where(opts.bounds);
+    let ptr_deref = opts.ptr_deref.is_some();
+    let body = access.render(
+        Receiver::Ref,
+        move |place| {
+            let place = option_place(place, ptr_deref);
+            let value = wrap_unsafe_read(access, ptr_deref, quote!(#place.get_or_insert_with(f)));
+            wrap_some(access, value)
+        },
+        quote!(::core::option::Option::None),
+    );
+
+    quote! {
+        (&mut self, f: impl ::core::ops::FnOnce() -> #inner) -> #fn_return #where_clause {
+            #body
+        }
+    }
+};
+
+type RenderBoolFn = fn(&FieldAccess, FinalOptions) -> TokenStream;
+
+const RENDER_TOGGLE: RenderBoolFn = |access, opts| {
+    let ptr_deref = opts.ptr_deref;
+    render_in_place(access, opts, None, move |place| {
+        if ptr_deref.is_some() {
+            quote! { unsafe { *#place = !*#place; } }
+        } else {
+            wrap_union(access, None, quote! { #place = !#place; })
+        }
+    })
+};
+
+const RENDER_ENABLE: RenderBoolFn = |access, opts| render_bool_value(access, opts, true);
+
+const RENDER_DISABLE: RenderBoolFn = |access, opts| render_bool_value(access, opts, false);
+
+fn render_bool_value(access: &FieldAccess, opts: FinalOptions, value: bool) -> TokenStream {
+    let ptr_deref = opts.ptr_deref;
+    render_in_place(access, opts, None, move |place| {
+        if ptr_deref.is_some() {
+            quote! { unsafe { *#place = #value; } }
+        } else {
+            quote! { #place = #value; }
+        }
+    })
+}
+
+/// Render a `(&mut self) -> &mut Self` accessor that modifies the field in place
+fn render_in_place<F>(
+    access: &FieldAccess,
+    opts: FinalOptions,
+    params: Option<TokenStream>,
+    write: F,
+) -> TokenStream
+where
+    F: FnOnce(TokenStream) -> TokenStream,
+{
+    let params = params.map(move |params| quote!(, #params));
+    let where_clause = mk_where(opts.bounds);
+    let (receiver, fn_params, self_ty, fallback) = if opts.owned {
+        let fallback = quote!(return ::core::result::Result::Err(self));
+        (Receiver::OwnedMut, quote!(mut self), quote!(Self), fallback)
+    } else {
+        let fallback = quote!(return ::core::option::Option::None);
+        (
+            Receiver::Ref,
+            quote!(&mut self),
+            quote!(&mut Self),
+            fallback,
+        )
+    };
+
+    let write = access.render(
+        receiver,
+        move |place| {
+            let write = write(place);
+            quote!({ #write })
+        },
+        fallback,
+    );
+
+    if access.exhaustive {
+        quote! {
+            (#fn_params #params) -> #self_ty #where_clause {
+                #write
+                self
+            }
+        }
+    } else if opts.owned {
+        quote! {
+            (#fn_params #params) -> ::core::result::Result<Self, Self> #where_clause {
+                #write;
+                ::core::result::Result::Ok(self)
+            }
+        }
+    } else {
+        quote! {
+            (#fn_params #params) -> ::core::option::Option<&mut Self> #where_clause {
+                #write;
+                ::core::option::Option::Some(self)
+            }
+        }
+    }
+}
+
+/// Union field reads need an `unsafe` block unless `ptr_deref` already added one
+fn wrap_union(
+    access: &FieldAccess,
+    ptr_deref: Option<DerefKind>,
+    value: TokenStream,
+) -> TokenStream {
+    if access.union && ptr_deref.is_none() {
+        quote!(unsafe { #value })
+    } else {
+        value
+    }
+}
+
+fn wrap_some(access: &FieldAccess, value: TokenStream) -> TokenStream {
+    if access.exhaustive {
+        value
+    } else {
+        quote!(::core::option::Option::Some(#value))
+    }
+}
+
+type RenderVariantFn = fn(&VariantShape, bool, FinalOptions) -> TokenStream;
+
+const RENDER_IS_VARIANT: RenderVariantFn = |variant, _, opts| {
+    let ident = &variant.ident;
+    let where_clause = mk_where(opts.bounds);
+
+    quote!((&self) -> bool #where_clause { ::core::matches!(self, Self::#ident { .. }) })
+};
+
+const RENDER_AS_VARIANT: RenderVariantFn = |variant, catch_all, opts| {
+    let (pattern, value, value_ty) = variant_value(variant, &quote!(&));
+    let where_clause = mk_where(opts.bounds);
+    let catch_all = mk_catch_all(catch_all, &quote!(::core::option::Option::None));
+
+    quote! {
+        (&self) -> ::core::option::Option<#value_ty> #where_clause {
+            match self {
+                #pattern => ::core::option::Option::Some(#value),
+                #catch_all
+            }
+        }
+    }
+};
+
+const RENDER_AS_VARIANT_MUT: RenderVariantFn = |variant, catch_all, opts| {
+    let (pattern, value, value_ty) = variant_value(variant, &quote!(&mut));
+    let where_clause = mk_where(opts.bounds);
+    let catch_all = mk_catch_all(catch_all, &quote!(::core::option::Option::None));
+
+    quote! {
+        (&mut self) -> ::core::option::Option<#value_ty> #where_clause {
+            match self {
+                #pattern => ::core::option::Option::Some(#value),
+                #catch_all
+            }
+        }
+    }
+};
+
+const RENDER_INTO_VARIANT: RenderVariantFn = |variant, catch_all, opts| {
+    let (pattern, value, value_ty) = variant_value(variant, &TokenStream::new());
+    let where_clause = mk_where(opts.bounds);
+    let catch_all = mk_catch_all(catch_all, &quote!(::core::result::Result::Err(self)));
+
+    quote! {
+        (self) -> ::core::result::Result<#value_ty, Self> #where_clause {
+            match self {
+                #pattern => ::core::result::Result::Ok(#value),
+                #catch_all
+            }
+        }
+    }
+};
+
+/// Returns the variant's pattern, the value to return & its type
+fn variant_value(
+    variant: &VariantShape,
+    ref_tokens: &TokenStream,
+) -> (TokenStream, TokenStream, TokenStream) {
+    let (pattern, bindings) = variant.pattern();
+    let mut types = variant.field_types();
+
+    if bindings.len() == 1 {
+        let ty = types.next().unwrap();
+        let binding = &bindings[0];
+        (pattern, quote!(#binding), quote!(#ref_tokens #ty))
+    } else {
+        (
+            pattern,
+            quote!((#(#bindings),*)),
+            quote!((#(#ref_tokens #types),*)),
+        )
+    }
+}
+
+fn mk_catch_all(enabled: bool, body: &TokenStream) -> Option<TokenStream> {
+    if enabled {
+        Some(quote!(_ => #body,))
+    } else {
+        None
+    }
+}
+
 impl Parse for DeriveAccessors {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let DeriveInput {
@@ -288,15 +1553,62 @@ impl Parse for DeriveAccessors {
             ..
         } = input.parse()?;
 
-        let container_opts = ContainerOptions::from_iter_named(ATTR_NAME, ident.span(), attrs)?;
-        let fields = data
-            .extract_struct_named()?
-            .into_iter()
-            .map(ParsedField::try_from);
+        let (attrs, when_rules) = WhenRule::extract(ATTR_NAME, attrs)?;
+        let mut container_opts = ContainerOptions::from_iter_named(ATTR_NAME, ident.span(), attrs)?;
+        if let Some(preset) = container_opts.preset {
+            preset.apply(&mut container_opts.defaults);
+        }
+
+        let (fields, variants, struct_shape) = match data {
+            Data::Union(data) => {
+                let fields = data.fields.named.into_iter().enumerate().map(move |field| {
+                    let mut field = ParsedField::try_from(field)?;
+                    field.access.union = true;
+                    Ok(field)
+                });
+
+                (fields.collect::<syn::Result<_>>()?, Vec::new(), None)
+            }
+            Data::Enum(data) => {
+                let variants = data.variants.iter().map(ParsedVariant::try_from);
+                let variants = variants.collect::<syn::Result<_>>()?;
+
+                (ParsedField::from_variants(data.variants)?, variants, None)
+            }
+            Data::Struct(data) => {
+                let fields = data
+                    .fields
+                    .extract_any_fields()?
+                    .into_iter()
+                    .enumerate()
+                    .map(ParsedField::try_from);
+
+                let fields = fields.collect::<syn::Result<Vec<_>>>()?;
+                let shape = StructShape {
+                    ident: ident.clone(),
+                    generics: generics.clone(),
+                    fields: fields
+                        .iter()
+                        .map(move |f| (f.access.member.clone(), f.ty.clone()))
+                        .collect(),
+                };
+
+                (fields, Vec::new(), Some(shape))
+            }
+        };
+
+        for field in &fields {
+            field.check_bool_accessors()?;
+            field.check_option_accessors()?;
+            field.check_getter_conversions()?;
+        }
 
         Ok(Self {
-            fields: fields.collect::<syn::Result<_>>()?,
+            fields,
+            variants,
+            struct_shape,
             container_opts,
+            when_rules,
             ident,
             generics,
         })
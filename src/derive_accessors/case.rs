@@ -0,0 +1,58 @@
+use proc_macro2::{Ident, Span};
+
+/// Convert a `PascalCase` or `camelCase` name to `snake_case`
+pub fn to_snake_case(input: &str) -> String {
+    let chars: Vec<char> = input.chars().collect();
+    let mut out = String::with_capacity(input.len() + 4);
+
+    for (idx, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() {
+            let prev = idx.checked_sub(1).map(|i| chars[i]);
+            let next = chars.get(idx + 1).copied();
+
+            let boundary = match prev {
+                None | Some('_') => false,
+                Some(p) if p.is_lowercase() || p.is_ascii_digit() => true,
+                Some(p) => p.is_uppercase() && next.is_some_and(char::is_lowercase),
+            };
+            if boundary {
+                out.push('_');
+            }
+            out.extend(c.to_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+
+    out
+}
+
+/// Keywords that can be used as raw identifiers
+const RAW_KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do", "dyn",
+    "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl", "in", "let",
+    "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return",
+    "static", "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use",
+    "virtual", "where", "while", "yield",
+];
+
+/// Keywords that can't be used as raw identifiers either
+const NON_RAW_KEYWORDS: &[&str] = &["crate", "self", "Self", "super", "_"];
+
+/// Check whether the name can be made into an identifier by [`to_ident`]
+pub fn is_ident(name: &str) -> bool {
+    name.starts_with(|c: char| c == '_' || c.is_alphabetic())
+        && name.chars().all(move |c| c == '_' || c.is_alphanumeric())
+}
+
+/// Create an identifier from a name, making it a raw identifier if it's a keyword & appending an
+/// underscore if it's a keyword that can't be raw
+pub fn to_ident(name: &str, span: Span) -> Ident {
+    if RAW_KEYWORDS.contains(&name) {
+        Ident::new_raw(name, span)
+    } else if NON_RAW_KEYWORDS.contains(&name) {
+        Ident::new(&format!("{name}_"), span)
+    } else {
+        Ident::new(name, span)
+    }
+}
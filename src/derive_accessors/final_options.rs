@@ -3,42 +3,182 @@ use proc_macro2::Ident;
 use syn::punctuated::Punctuated;
 use syn::{Token, Visibility, WherePredicate};
 
-use super::options::{DerefKind, SkippableIdent};
+use super::options::{
+    Conversion, Converter, DefaultValue, DerefKind, NameTemplate, Returns, SkippableIdent,
+};
 use super::{VariationDefaults, VariationOptions};
 
 #[cfg_attr(feature = "_debug", derive(Debug))]
 pub struct FinalOptions {
+    pub name: Option<Ident>,
+    pub aliases: Punctuated<Ident, Token![,]>,
     pub owned: bool,
     pub const_fn: bool,
     pub skip: bool,
     pub cp: bool,
     pub as_ref: bool,
+    pub deref: bool,
+    pub as_deref: bool,
+    pub into: bool,
+    pub some: bool,
+    pub returns: Option<Returns>,
+    pub conv: Option<Conversion>,
+    pub map: Option<syn::Path>,
+    pub retype: bool,
+    pub default: Option<DefaultValue>,
+    pub from: Option<Converter>,
+    pub try_from: Option<Converter>,
+    pub error: Option<syn::Type>,
     pub ptr_deref: Option<DerefKind>,
     pub vis: Visibility,
     pub prefix: Option<SkippableIdent>,
     pub suffix: Option<SkippableIdent>,
+    pub name_template: Option<NameTemplate>,
+    pub kind: &'static str,
+    pub bool_getter: bool,
     pub ty: Option<syn::Type>,
     pub bounds: Punctuated<WherePredicate, Token![,]>,
+
+    /// Makes the accessor an `unsafe fn` with this text in its `# Safety` doc section
+    pub safety: Option<String>,
 }
 
 #[cfg_attr(feature = "_debug", derive(Debug))]
 pub struct Naming {
+    pub kind: &'static str,
     pub prefix: Option<&'static str>,
     pub suffix: Option<&'static str>,
+
+    /// Whether this is the getter of a `bool` field
+    pub bool_getter: bool,
 }
 
 impl Naming {
     pub const GET: Self = Self {
+        kind: "get",
+        prefix: None,
+        suffix: None,
+        bool_getter: false,
+    };
+    pub const GET_BOOL: Self = Self {
+        kind: "get",
         prefix: None,
         suffix: None,
+        bool_getter: true,
     };
     pub const GET_MUT: Self = Self {
+        kind: "get_mut",
         prefix: None,
         suffix: Some("mut"),
+        bool_getter: false,
     };
     pub const SET: Self = Self {
+        kind: "set",
         prefix: Some("set"),
         suffix: None,
+        bool_getter: false,
+    };
+    pub const WITH: Self = Self {
+        kind: "with",
+        prefix: Some("with"),
+        suffix: None,
+        bool_getter: false,
+    };
+    pub const REPLACE: Self = Self {
+        kind: "replace",
+        prefix: Some("replace"),
+        suffix: None,
+        bool_getter: false,
+    };
+    pub const TAKE: Self = Self {
+        kind: "take",
+        prefix: Some("take"),
+        suffix: None,
+        bool_getter: false,
+    };
+    pub const SWAP: Self = Self {
+        kind: "swap",
+        prefix: Some("swap"),
+        suffix: None,
+        bool_getter: false,
+    };
+    pub const UPDATE: Self = Self {
+        kind: "update",
+        prefix: Some("update"),
+        suffix: None,
+        bool_getter: false,
+    };
+    pub const MAP: Self = Self {
+        kind: "map",
+        prefix: Some("map"),
+        suffix: None,
+        bool_getter: false,
+    };
+    pub const IS_SOME: Self = Self {
+        kind: "is_some",
+        prefix: Some("is"),
+        suffix: Some("some"),
+        bool_getter: false,
+    };
+    pub const CLEAR: Self = Self {
+        kind: "clear",
+        prefix: Some("clear"),
+        suffix: None,
+        bool_getter: false,
+    };
+    pub const OR: Self = Self {
+        kind: "or",
+        prefix: None,
+        suffix: Some("or"),
+        bool_getter: false,
+    };
+    pub const OR_INSERT_WITH: Self = Self {
+        kind: "or_insert_with",
+        prefix: None,
+        suffix: Some("or_insert_with"),
+        bool_getter: false,
+    };
+    pub const TOGGLE: Self = Self {
+        kind: "toggle",
+        prefix: Some("toggle"),
+        suffix: None,
+        bool_getter: false,
+    };
+    pub const ENABLE: Self = Self {
+        kind: "enable",
+        prefix: Some("enable"),
+        suffix: None,
+        bool_getter: false,
+    };
+    pub const DISABLE: Self = Self {
+        kind: "disable",
+        prefix: Some("disable"),
+        suffix: None,
+        bool_getter: false,
+    };
+    pub const IS_VARIANT: Self = Self {
+        kind: "is_variant",
+        prefix: Some("is"),
+        suffix: None,
+        bool_getter: false,
+    };
+    pub const AS_VARIANT: Self = Self {
+        kind: "as_variant",
+        prefix: Some("as"),
+        suffix: None,
+        bool_getter: false,
+    };
+    pub const AS_VARIANT_MUT: Self = Self {
+        kind: "as_variant_mut",
+        prefix: Some("as"),
+        suffix: Some("mut"),
+        bool_getter: false,
+    };
+    pub const INTO_VARIANT: Self = Self {
+        kind: "into_variant",
+        prefix: Some("into"),
+        suffix: None,
+        bool_getter: false,
     };
 }
 
@@ -51,7 +191,7 @@ impl FinalOptions {
         opts_all_field: Option<&VariationOptions>,
         opts_all_container: &VariationDefaults,
     ) -> Option<Self> {
-        let mut opts = match (enable_by_default, opts) {
+        let (mut opts, field_affixes) = match (enable_by_default, opts) {
             (
                 _,
                 Some(VariationOptions {
@@ -60,42 +200,95 @@ impl FinalOptions {
             ) if skip => return None,
             (_, Some(mut opts)) => {
                 opts.assign_defaults_from_prop_all(opts_all_field);
-                opts.assign_defaults_from_struct(defaults_from_struct);
-                opts
+                let field_affixes = opts.has_affixes();
+                (opts, field_affixes)
             }
             (true, None) => {
                 if let Some(opts_all_field) = opts_all_field {
                     let mut opts_all_field = opts_all_field.clone();
-                    opts_all_field.assign_defaults_from_struct(defaults_from_struct);
-                    opts_all_field
+                    // `all(name)` is the base name, not the full one & aliases would clash
+                    opts_all_field.name = None;
+                    opts_all_field.alias.clear();
+                    opts_all_field.returns = None;
+                    let field_affixes = opts_all_field.has_affixes();
+                    (opts_all_field, field_affixes)
                 } else {
-                    defaults_from_struct.into()
+                    (defaults_from_struct.into(), false)
                 }
             }
             _ => return None,
         };
-        opts.assign_defaults_from_struct(opts_all_container);
+        opts.assign_defaults_from_struct(defaults_from_struct);
+        opts.assign_defaults_from_struct_all(opts_all_container);
+
+        // Only getters convert their value
+        if defaults_for_variation.kind != "get" {
+            opts.conv = None;
+            opts.map = None;
+        }
+
+        // A prefix/suffix set on the field beats a name template inherited from the container
+        if field_affixes {
+            opts.name_template = None;
+        }
 
         let mut out = Self {
+            name: opts.name,
+            aliases: opts.alias,
             owned: opts.owned.unwrap_or_default(),
             const_fn: opts.const_fn.unwrap_or_default(),
             skip: opts.skip.unwrap_or_default(),
             cp: opts.cp.unwrap_or_default(),
             as_ref: opts.as_ref.unwrap_or_default(),
+            deref: opts.deref.unwrap_or_default(),
+            as_deref: opts.as_deref.unwrap_or_default(),
+            into: opts.into.unwrap_or_default(),
+            some: opts.some.unwrap_or_default(),
+            returns: opts.returns,
+            conv: opts.conv,
+            map: opts.map,
+            retype: opts.retype.unwrap_or_default(),
+            default: opts.default,
+            from: opts.from,
+            try_from: opts.try_from,
+            error: opts.error,
             ptr_deref: opts.ptr_deref,
             vis: opts
                 .vis
                 .unwrap_or_else(move || Visibility::Public(Default::default())),
             prefix: opts.prefix,
             suffix: opts.suffix,
+            name_template: opts.name_template,
+            kind: defaults_for_variation.kind,
+            bool_getter: defaults_for_variation.bool_getter,
             ty: opts.ty,
             bounds: opts.bounds,
+            safety: None,
         };
         out.apply_naming_defaults(defaults_for_variation);
+        out.drop_non_const();
 
         Some(out)
     }
 
+    /// Turn `const_fn` off for accessors whose body can't run in a const fn
+    fn drop_non_const(&mut self) {
+        let non_const = match self.kind {
+            // Deref coercions call `Deref::deref` & conversions call trait methods or functions
+            "get" | "get_mut" => {
+                self.deref || self.as_deref || self.conv.is_some() || self.map.is_some()
+            }
+            // Trait-based conversions of the new value
+            "set" | "with" | "replace" => {
+                self.into || self.from.is_some() || self.try_from.is_some()
+            }
+            _ => false,
+        };
+        if non_const {
+            self.const_fn = false;
+        }
+    }
+
     pub fn apply_naming_defaults(&mut self, defaults: &'static Naming) {
         if self.prefix.is_none() {
             if let Some(v) = defaults.prefix {
@@ -1,66 +1,255 @@
+use macroific::attr_parse::ValueSyntax;
 use macroific::prelude::*;
-use proc_macro2::{Ident, Punct, TokenStream};
+use proc_macro2::{Delimiter, Ident, Punct, Span, TokenStream, TokenTree};
 use quote::{quote, ToTokens};
+use std::mem;
+use syn::ext::IdentExt;
 use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
 use syn::{Error, Expr, LitStr, Token, Visibility, WherePredicate};
 
+use super::case::to_snake_case;
+use super::types;
+
 #[derive(AttributeOptions)]
 #[cfg_attr(feature = "_debug", derive(Debug))]
 pub struct ContainerOptions {
     pub get: bool,
     pub get_mut: bool,
     pub set: bool,
+    pub with: bool,
+    pub replace: bool,
+    pub take: bool,
+    pub swap: bool,
+    pub update: bool,
+    pub map: bool,
+    pub option: bool,
+    pub is_variant: bool,
+    pub as_variant: bool,
+    pub as_variant_mut: bool,
+    pub into_variant: bool,
+    pub toggle: bool,
+    pub enable: bool,
+    pub disable: bool,
 
     pub defaults: ContainerDefaults,
     pub bounds: Punctuated<WherePredicate, Token![,]>,
+    pub rename_all: Option<RenameRule>,
+    pub strip_prefix: Option<StripPrefix>,
+    pub preset: Option<Preset>,
+    pub smart: Option<SmartOptions>,
 }
 
 #[derive(AttributeOptions)]
 #[cfg_attr(feature = "_debug", derive(Debug))]
 pub struct FieldOptions {
     pub skip: bool,
+    pub name: Option<Ident>,
+    pub rename_all: Option<RenameRule>,
+    pub strip_prefix: Option<StripPrefix>,
+    pub bool: Option<bool>,
+    pub option: Option<bool>,
     pub all: Option<VariationOptions>,
     pub get: Option<VariationOptions>,
     pub get_mut: Option<VariationOptions>,
     pub set: Option<VariationOptions>,
+    pub with: Option<VariationOptions>,
+    pub replace: Option<VariationOptions>,
+    pub take: Option<VariationOptions>,
+    pub swap: Option<VariationOptions>,
+    pub update: Option<VariationOptions>,
+    pub map: Option<VariationOptions>,
+    pub is_some: Option<VariationOptions>,
+    pub clear: Option<VariationOptions>,
+    pub or: Option<VariationOptions>,
+    pub or_insert_with: Option<VariationOptions>,
+    pub toggle: Option<VariationOptions>,
+    pub enable: Option<VariationOptions>,
+    pub disable: Option<VariationOptions>,
 }
 
-#[derive(ParseOption, Default)]
+#[derive(AttributeOptions)]
+#[cfg_attr(feature = "_debug", derive(Debug))]
+pub struct VariantOptions {
+    pub skip: bool,
+    pub name: Option<Ident>,
+    pub all: Option<VariationOptions>,
+    pub is_variant: Option<VariationOptions>,
+    pub as_variant: Option<VariationOptions>,
+    pub as_variant_mut: Option<VariationOptions>,
+    pub into_variant: Option<VariationOptions>,
+}
+
+#[derive(ParseOption, Default, Clone)]
 #[cfg_attr(feature = "_debug", derive(Debug))]
 pub struct ContainerDefaults {
     pub all: VariationDefaults,
     pub get: VariationDefaults,
+    pub get_bool: VariationDefaults,
     pub get_mut: VariationDefaults,
     pub set: VariationDefaults,
+    pub with: VariationDefaults,
+    pub replace: VariationDefaults,
+    pub take: VariationDefaults,
+    pub swap: VariationDefaults,
+    pub update: VariationDefaults,
+    pub map: VariationDefaults,
+    pub is_some: VariationDefaults,
+    pub clear: VariationDefaults,
+    pub or: VariationDefaults,
+    pub or_insert_with: VariationDefaults,
+    pub toggle: VariationDefaults,
+    pub enable: VariationDefaults,
+    pub disable: VariationDefaults,
+    pub is_variant: VariationDefaults,
+    pub as_variant: VariationDefaults,
+    pub as_variant_mut: VariationDefaults,
+    pub into_variant: VariationDefaults,
+}
+
+/// `when(ty = Pattern, get(...), ...)`: accessor defaults for fields whose type matches the pattern
+#[cfg_attr(feature = "_debug", derive(Debug))]
+pub struct WhenRule {
+    pub ty: syn::Type,
+    pub defaults: ContainerDefaults,
+}
+
+impl Parse for WhenRule {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let label: Ident = input.parse()?;
+        if label != "ty" {
+            return Err(Error::new_spanned(label, "Expected `ty = Type`"));
+        }
+        input.parse::<Token![=]>()?;
+        let ty = input.parse()?;
+
+        let defaults = if input.is_empty() {
+            ContainerDefaults::default()
+        } else {
+            input.parse::<Token![,]>()?;
+            let rest: TokenStream = input.parse()?;
+            syn::parse2(quote!((#rest)))?
+        };
+
+        Ok(Self { ty, defaults })
+    }
+}
+
+impl WhenRule {
+    /// Pull the `when(...)` rules out of the container's `#[access]` attributes, which can repeat them
+    pub fn extract(
+        attr_name: &str,
+        attrs: Vec<syn::Attribute>,
+    ) -> syn::Result<(Vec<syn::Attribute>, Vec<Self>)> {
+        let mut rules = Vec::new();
+        let mut out = Vec::with_capacity(attrs.len());
+
+        for mut attr in attrs {
+            if let syn::Meta::List(ref mut list) = attr.meta {
+                if list.path.is_ident(attr_name) {
+                    let mut kept = TokenStream::new();
+                    for entry in split_entries(mem::take(&mut list.tokens)) {
+                        let mut iter = entry.clone().into_iter();
+                        match (iter.next(), iter.next(), iter.next()) {
+                            (
+                                Some(TokenTree::Ident(ident)),
+                                Some(TokenTree::Group(group)),
+                                None,
+                            ) if ident == "when" && group.delimiter() == Delimiter::Parenthesis => {
+                                rules.push(syn::parse2(group.stream())?);
+                            }
+                            _ => {
+                                if !kept.is_empty() {
+                                    <Token![,]>::default().to_tokens(&mut kept);
+                                }
+                                kept.extend(entry);
+                            }
+                        }
+                    }
+                    list.tokens = kept;
+                }
+            }
+            out.push(attr);
+        }
+
+        Ok((out, rules))
+    }
+}
+
+/// Split an attribute's tokens on top-level commas
+fn split_entries(tokens: TokenStream) -> Vec<TokenStream> {
+    let mut entries = vec![TokenStream::new()];
+    for token in tokens {
+        match token {
+            TokenTree::Punct(ref punct) if punct.as_char() == ',' => {
+                entries.push(TokenStream::new());
+            }
+            token => entries.last_mut().unwrap().extend([token]),
+        }
+    }
+    entries.retain(move |entry| !entry.is_empty());
+    entries
+}
+
+/// `smart(copy(...), deref(...), as_ref(...))`: types getting `get` defaults inferred on top of the
+/// built-in ones
+#[derive(ParseOption, Default)]
+#[cfg_attr(feature = "_debug", derive(Debug))]
+pub struct SmartOptions {
+    pub copy: Punctuated<syn::Type, Token![,]>,
+    pub deref: Punctuated<syn::Type, Token![,]>,
+    pub as_ref: Punctuated<syn::Type, Token![,]>,
 }
 
 #[derive(ParseOption, Clone)]
 #[cfg_attr(feature = "_debug", derive(Debug))]
 pub struct VariationOptions {
+    pub name: Option<Ident>,
+    pub alias: Punctuated<Ident, Token![,]>,
     pub owned: Option<bool>,
     pub const_fn: Option<bool>,
     pub skip: Option<bool>,
     pub cp: Option<bool>,
     pub as_ref: Option<bool>,
+    pub deref: Option<bool>,
+    pub as_deref: Option<bool>,
+    pub into: Option<bool>,
+    pub some: Option<bool>,
+    pub returns: Option<Returns>,
+    pub conv: Option<Conversion>,
+    pub map: Option<syn::Path>,
+    pub retype: Option<bool>,
+    pub default: Option<DefaultValue>,
+    pub from: Option<Converter>,
+    pub try_from: Option<Converter>,
+    pub error: Option<syn::Type>,
     pub ptr_deref: Option<DerefKind>,
     pub ty: Option<syn::Type>,
     pub prefix: Option<SkippableIdent>,
     pub suffix: Option<SkippableIdent>,
+    pub name_template: Option<NameTemplate>,
     pub vis: Option<Visibility>,
     pub bounds: Punctuated<WherePredicate, Token![,]>,
 }
 
-#[derive(ParseOption, Default)]
+#[derive(ParseOption, Default, Clone)]
 #[cfg_attr(feature = "_debug", derive(Debug))]
 pub struct VariationDefaults {
     pub owned: Option<bool>,
     pub const_fn: Option<bool>,
     pub cp: Option<bool>,
     pub as_ref: Option<bool>,
+    pub deref: Option<bool>,
+    pub as_deref: Option<bool>,
+    pub into: Option<bool>,
+    pub some: Option<bool>,
+    pub returns: Option<Returns>,
+    pub conv: Option<Conversion>,
+    pub map: Option<syn::Path>,
     pub ptr_deref: Option<DerefKind>,
     pub prefix: Option<SkippableIdent>,
     pub suffix: Option<SkippableIdent>,
+    pub name_template: Option<NameTemplate>,
     pub vis: Option<Visibility>,
     pub bounds: Punctuated<WherePredicate, Token![,]>,
 }
@@ -74,18 +263,116 @@ impl FromExpr for VariationDefaults {
     }
 }
 
+impl ContainerDefaults {
+    /// The defaults of each accessor type, excluding `all` & `get_bool`, which falls back to `get`
+    fn kinds_mut(&mut self) -> [&mut VariationDefaults; 20] {
+        [
+            &mut self.get,
+            &mut self.get_mut,
+            &mut self.set,
+            &mut self.with,
+            &mut self.replace,
+            &mut self.take,
+            &mut self.swap,
+            &mut self.update,
+            &mut self.map,
+            &mut self.is_some,
+            &mut self.clear,
+            &mut self.or,
+            &mut self.or_insert_with,
+            &mut self.toggle,
+            &mut self.enable,
+            &mut self.disable,
+            &mut self.is_variant,
+            &mut self.as_variant,
+            &mut self.as_variant_mut,
+            &mut self.into_variant,
+        ]
+    }
+
+    /// Layer these defaults on top of `fallback`, with this `all` beating `fallback`'s accessor type
+    /// defaults
+    pub fn or(&self, fallback: &Self) -> Self {
+        macro_rules! layer {
+            ($($kind: ident),+ $(,)?) => {
+                Self {
+                    all: self.all.or(&fallback.all),
+                    $($kind: self.$kind.or_all(&self.all).or(&fallback.$kind),)+
+                }
+            };
+        }
+
+        layer!(
+            get,
+            get_bool,
+            get_mut,
+            set,
+            with,
+            replace,
+            take,
+            swap,
+            update,
+            map,
+            is_some,
+            clear,
+            or,
+            or_insert_with,
+            toggle,
+            enable,
+            disable,
+            is_variant,
+            as_variant,
+            as_variant_mut,
+            into_variant,
+        )
+    }
+}
+
+impl SmartOptions {
+    /// Infer `get` defaults from the field's type
+    pub fn infer(&self, ty: &syn::Type) -> Option<VariationDefaults> {
+        let mut out = VariationDefaults::default();
+        if types::option_inner(ty).is_some() || types::is_listed(&self.as_ref, ty) {
+            out.as_ref = Some(true);
+        } else if types::is_smart_copy(ty) || types::is_listed(&self.copy, ty) {
+            out.cp = Some(true);
+        } else if types::is_smart_deref(ty) || types::is_listed(&self.deref, ty) {
+            out.deref = Some(true);
+        } else {
+            return None;
+        }
+
+        Some(out)
+    }
+}
+
 impl From<&VariationDefaults> for VariationOptions {
     fn from(defaults: &VariationDefaults) -> Self {
         Self {
+            name: None,
+            alias: Punctuated::new(),
             owned: defaults.owned,
             const_fn: defaults.const_fn,
             skip: None,
             cp: defaults.cp,
             as_ref: defaults.as_ref,
+            deref: defaults.deref,
+            as_deref: defaults.as_deref,
+            into: defaults.into,
+            some: defaults.some,
+            returns: defaults.returns,
+            conv: defaults.conv,
+            map: defaults.map.clone(),
+            retype: None,
+            default: None,
+            from: None,
+            try_from: None,
+            error: None,
             ptr_deref: defaults.ptr_deref,
             ty: None,
             prefix: defaults.prefix.clone(),
             suffix: defaults.suffix.clone(),
+            name_template: defaults.name_template.clone(),
             vis: defaults.vis.clone(),
             bounds: defaults.bounds.clone(),
         }
@@ -112,15 +399,27 @@ macro_rules! assign_defaults {
         )+
     };
     ($from: ident on $self: ident) => {
-        assign_defaults!(cp $from on $self => owned, const_fn, cp, ptr_deref);
-        assign_defaults!(clone $from on $self => prefix, suffix, vis);
+        assign_defaults!(cp $from on $self => owned, const_fn, cp, as_ref, deref, as_deref, into, some, conv, ptr_deref);
+        assign_defaults!(clone $from on $self => map, prefix, suffix, name_template, vis);
         $self.apply_default_bounds(&$from.bounds);
     };
 }
 
 impl VariationOptions {
+    /// Whether a prefix or suffix is set without a name template
+    pub fn has_affixes(&self) -> bool {
+        self.name_template.is_none() && (self.prefix.is_some() || self.suffix.is_some())
+    }
+
     pub fn assign_defaults_from_struct(&mut self, defaults: &VariationDefaults) {
         assign_defaults!(defaults on self);
+        assign_defaults!(cp defaults on self => returns);
+    }
+
+    /// Like [`assign_defaults_from_struct`](Self::assign_defaults_from_struct), but for `all`,
+    /// which doesn't pass on `returns`
+    pub fn assign_defaults_from_struct_all(&mut self, defaults: &VariationDefaults) {
+        assign_defaults!(defaults on self);
     }
 
     pub fn assign_defaults_from_prop_all(&mut self, defaults: Option<&Self>) {
@@ -136,6 +435,37 @@ impl VariationOptions {
     }
 }
 
+impl VariationDefaults {
+    /// Whether these defaults pick a getter's return style, opting fields out of `smart`
+    pub fn sets_return_style(&self) -> bool {
+        [self.cp, self.owned, self.as_ref, self.deref, self.as_deref]
+            .into_iter()
+            .any(move |flag| flag.is_some())
+            || self.conv.is_some()
+            || self.map.is_some()
+    }
+
+    /// Layer these defaults on top of `fallback`
+    pub fn or(&self, fallback: &Self) -> Self {
+        let mut out = self.or_all(fallback);
+        assign_defaults!(cp fallback on out => returns);
+        out
+    }
+
+    /// Layer these defaults on top of `all`, which doesn't pass on `returns`
+    fn or_all(&self, all: &Self) -> Self {
+        let mut out = self.clone();
+        assign_defaults!(all on out);
+        out
+    }
+
+    fn apply_default_bounds(&mut self, default_bounds: &Punctuated<WherePredicate, Token![,]>) {
+        if self.bounds.is_empty() && !default_bounds.is_empty() {
+            self.bounds.clone_from(default_bounds);
+        }
+    }
+}
+
 #[derive(Copy, Clone)]
 #[cfg_attr(feature = "_debug", derive(Debug))]
 pub enum DerefKind {
@@ -187,6 +517,183 @@ impl DerefKind {
     }
 }
 
+/// What a setter returns: `returns = ref | owned | unit | old`
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "_debug", derive(Debug))]
+pub struct Returns {
+    pub style: ReturnStyle,
+    span: Span,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "_debug", derive(Debug))]
+pub enum ReturnStyle {
+    /// `&mut Self`
+    Ref,
+
+    /// `Self`
+    Owned,
+
+    /// `()`
+    Unit,
+
+    /// The previous value
+    Old,
+}
+
+/// How a getter converts the field's value: `conv = clone | copied | into | to_owned`
+#[derive(Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "_debug", derive(Debug))]
+pub enum Conversion {
+    /// [`Clone::clone`], or [`Option::cloned`] on `Option` refs
+    Clone,
+
+    /// Dereference the value, or [`Option::copied`] on `Option` refs
+    Copied,
+
+    /// [`Into::into`]
+    Into,
+
+    /// `ToOwned::to_owned`
+    ToOwned,
+}
+
+impl Parse for Conversion {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident = input.call(Ident::parse_any)?;
+        match ident.unraw().to_string().as_str() {
+            "clone" => Ok(Self::Clone),
+            "copied" => Ok(Self::Copied),
+            "into" => Ok(Self::Into),
+            "to_owned" => Ok(Self::ToOwned),
+            _ => Err(Error::new_spanned(
+                ident,
+                "Expected `clone`, `copied`, `into` or `to_owned`",
+            )),
+        }
+    }
+}
+
+impl FromExpr for Conversion {
+    fn from_expr(expr: Expr) -> syn::Result<Self> {
+        syn::parse2(expr.into_token_stream())
+    }
+}
+
+impl Parse for Returns {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident = input.call(Ident::parse_any)?;
+        let style = match ident.unraw().to_string().as_str() {
+            "ref" => ReturnStyle::Ref,
+            "owned" => ReturnStyle::Owned,
+            "unit" => ReturnStyle::Unit,
+            "old" => ReturnStyle::Old,
+            _ => {
+                return Err(Error::new_spanned(
+                    ident,
+                    "Expected `ref`, `owned`, `unit` or `old`",
+                ))
+            }
+        };
+
+        Ok(Self {
+            style,
+            span: ident.span(),
+        })
+    }
+}
+
+impl FromExpr for Returns {
+    fn from_expr(expr: Expr) -> syn::Result<Self> {
+        syn::parse2(expr.into_token_stream())
+    }
+}
+
+impl Returns {
+    /// Check the return style against the setter's receiver
+    pub fn check(self, owned: bool, union: bool) -> Result<(), TokenStream> {
+        let msg = match (self.style, owned) {
+            (ReturnStyle::Owned, false) => "`returns = owned` requires the `owned` option",
+            (ReturnStyle::Ref, true) => "`returns = ref` can't be combined with a `self` receiver",
+            (ReturnStyle::Unit, true) => {
+                "`returns = unit` can't be combined with a `self` receiver"
+            }
+            (ReturnStyle::Old, true) => "`returns = old` can't be combined with a `self` receiver",
+            (ReturnStyle::Old, false) if union => {
+                "`returns = old` isn't supported on union fields as the field might not be active"
+            }
+            _ => return Ok(()),
+        };
+
+        Err(Error::new(self.span, msg).into_compile_error())
+    }
+}
+
+/// How a setter converts its argument: `from` & `try_from` use the standard conversion traits,
+/// `from = path` & `try_from = path` call a function
+#[derive(Clone)]
+#[cfg_attr(feature = "_debug", derive(Debug))]
+pub enum Converter {
+    /// [`From`] or [`TryFrom`]
+    Trait,
+
+    /// A function taking the argument
+    Fn(syn::Path),
+}
+
+impl Parse for Converter {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.is_empty() {
+            Ok(Self::Trait)
+        } else {
+            input.parse().map(Self::Fn)
+        }
+    }
+}
+
+impl FromExpr for Converter {
+    fn from_expr(expr: Expr) -> syn::Result<Self> {
+        syn::Path::from_expr(expr).map(Self::Fn)
+    }
+
+    #[inline]
+    fn boolean() -> Option<Self> {
+        Some(Self::Trait)
+    }
+}
+
+/// The fallback of an `Option` getter: `default` or `default = expr`
+#[derive(Clone)]
+#[cfg_attr(feature = "_debug", derive(Debug))]
+pub enum DefaultValue {
+    /// [`Default::default()`]
+    Default,
+
+    /// A constant expression
+    Expr(Expr),
+}
+
+impl Parse for DefaultValue {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.is_empty() {
+            Ok(Self::Default)
+        } else {
+            input.parse().map(Self::Expr)
+        }
+    }
+}
+
+impl FromExpr for DefaultValue {
+    fn from_expr(expr: Expr) -> syn::Result<Self> {
+        Ok(Self::Expr(expr))
+    }
+
+    #[inline]
+    fn boolean() -> Option<Self> {
+        Some(Self::Default)
+    }
+}
+
 #[derive(Clone)]
 #[cfg_attr(feature = "_debug", derive(Debug))]
 pub enum SkippableIdent {
@@ -239,12 +746,13 @@ const _: () = {
             match self.ident {
                 SkippableIdent::Skip => Ok(()),
                 SkippableIdent::Ident(ident) => {
+                    let ident = ident.unraw();
                     if self.is_prefix {
-                        Display::fmt(ident, f)?;
+                        Display::fmt(&ident, f)?;
                         f.write_char(CHAR)
                     } else {
                         f.write_char(CHAR)?;
-                        Display::fmt(ident, f)
+                        Display::fmt(&ident, f)
                     }
                 }
             }
@@ -270,6 +778,234 @@ const _: () = {
     }
 };
 
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "_debug", derive(Debug))]
+pub enum RenameRule {
+    None,
+    SnakeCase,
+    Lowercase,
+}
+
+impl ParseOption for RenameRule {
+    fn from_stream(input: ParseStream) -> syn::Result<Self> {
+        let lit = <LitStr as ParseOption>::from_stream(input)?;
+        match lit.value().as_str() {
+            "none" => Ok(Self::None),
+            "snake_case" => Ok(Self::SnakeCase),
+            "lowercase" => Ok(Self::Lowercase),
+            _ => Err(Error::new_spanned(
+                lit,
+                "Expected \"snake_case\", \"lowercase\" or \"none\"",
+            )),
+        }
+    }
+}
+
+impl RenameRule {
+    pub fn apply(self, name: &str) -> String {
+        match self {
+            Self::None => name.to_owned(),
+            Self::SnakeCase => to_snake_case(name),
+            Self::Lowercase => name.to_lowercase(),
+        }
+    }
+}
+
+/// One or more prefixes to strip from field names: `= "lpsz"` or `("lpsz", "dw")`
+#[cfg_attr(feature = "_debug", derive(Debug))]
+pub struct StripPrefix(Vec<String>);
+
+impl ParseOption for StripPrefix {
+    fn from_stream(input: ParseStream) -> syn::Result<Self> {
+        let lits: Vec<LitStr> = match ValueSyntax::from_stream(input) {
+            Some(ValueSyntax::Paren) => {
+                let content;
+                syn::parenthesized!(content in input);
+                Punctuated::<LitStr, Token![,]>::parse_terminated(&content)?
+                    .into_iter()
+                    .collect()
+            }
+            _ => vec![<LitStr as ParseOption>::from_stream(input)?],
+        };
+
+        Ok(Self(lits.iter().map(LitStr::value).collect()))
+    }
+}
+
+impl StripPrefix {
+    /// Strip the first matching prefix that doesn't leave the name empty
+    pub fn apply<'a>(&self, name: &'a str) -> &'a str {
+        self.0
+            .iter()
+            .find_map(move |prefix| name.strip_prefix(prefix.as_str()).filter(|s| !s.is_empty()))
+            .unwrap_or(name)
+    }
+}
+
+/// A `"{kind}_{field}"`-style accessor name template
+#[derive(Clone)]
+#[cfg_attr(feature = "_debug", derive(Debug))]
+pub struct NameTemplate(Vec<TemplateSegment>, Span);
+
+#[derive(Clone)]
+#[cfg_attr(feature = "_debug", derive(Debug))]
+enum TemplateSegment {
+    Literal(String),
+    Field,
+    Kind,
+    Struct,
+}
+
+impl TryFrom<LitStr> for NameTemplate {
+    type Error = Error;
+
+    fn try_from(lit: LitStr) -> syn::Result<Self> {
+        let value = lit.value();
+        let mut segments = Vec::new();
+        let mut rest = value.as_str();
+
+        while let Some(start) = rest.find('{') {
+            if rest[..start].contains('}') {
+                return Err(Error::new_spanned(lit, "Unopened `}` in name template"));
+            }
+            if start != 0 {
+                segments.push(TemplateSegment::Literal(rest[..start].into()));
+            }
+
+            let Some(end) = rest[start..].find('}').map(move |end| start + end) else {
+                return Err(Error::new_spanned(lit, "Unclosed `{` in name template"));
+            };
+            segments.push(match &rest[start + 1..end] {
+                "field" => TemplateSegment::Field,
+                "kind" => TemplateSegment::Kind,
+                "struct" => TemplateSegment::Struct,
+                other => {
+                    let msg = format!(
+                        "Unknown placeholder `{{{other}}}`; expected `{{field}}`, `{{kind}}` or `{{struct}}`"
+                    );
+                    return Err(Error::new_spanned(lit, msg));
+                }
+            });
+            rest = &rest[end + 1..];
+        }
+
+        if rest.contains('}') {
+            return Err(Error::new_spanned(lit, "Unopened `}` in name template"));
+        }
+        if !rest.is_empty() {
+            segments.push(TemplateSegment::Literal(rest.into()));
+        }
+
+        Ok(Self(segments, lit.span()))
+    }
+}
+
+impl ParseOption for NameTemplate {
+    fn from_stream(input: ParseStream) -> syn::Result<Self> {
+        <LitStr as ParseOption>::from_stream(input)?.try_into()
+    }
+}
+
+impl FromExpr for NameTemplate {
+    fn from_expr(expr: Expr) -> syn::Result<Self> {
+        LitStr::from_expr(expr)?.try_into()
+    }
+}
+
+impl NameTemplate {
+    pub fn span(&self) -> Span {
+        self.1
+    }
+
+    pub fn render(&self, field: &str, kind: &str, struct_name: &str) -> String {
+        self.0
+            .iter()
+            .map(move |segment| match segment {
+                TemplateSegment::Literal(s) => s.as_str(),
+                TemplateSegment::Field => field,
+                TemplateSegment::Kind => kind,
+                TemplateSegment::Struct => struct_name,
+            })
+            .collect()
+    }
+}
+
+/// A named set of container defaults: `preset = java_bean`
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "_debug", derive(Debug))]
+pub enum Preset {
+    /// `get_foo`, `get_foo_mut`, `set_foo` & `is_foo` for `bool` fields
+    JavaBean,
+    /// `foo`, `foo_mut` & `set_foo` - same as having no preset
+    RustApi,
+    /// `my_struct_foo_get`, `my_struct_foo_get_mut` & `my_struct_foo_set`
+    CStyle,
+}
+
+impl ParseOption for Preset {
+    fn from_stream(input: ParseStream) -> syn::Result<Self> {
+        let ident = <Ident as ParseOption>::from_stream(input)?;
+        match ident.to_string().as_str() {
+            "java_bean" => Ok(Self::JavaBean),
+            "rust_api" => Ok(Self::RustApi),
+            "c_style" => Ok(Self::CStyle),
+            _ => Err(Error::new_spanned(
+                ident,
+                "Expected `java_bean`, `rust_api` or `c_style`",
+            )),
+        }
+    }
+}
+
+impl Preset {
+    /// Fill in whatever the container's `defaults` option left unset
+    pub fn apply(self, defaults: &mut ContainerDefaults) {
+        fn seed(slot: &mut Option<SkippableIdent>, value: &str) {
+            if slot.is_none() {
+                *slot = Some(SkippableIdent::Ident(Ident::create(value)));
+            }
+        }
+        fn is_named(kind: &VariationDefaults) -> bool {
+            kind.prefix.is_some() || kind.suffix.is_some() || kind.name_template.is_some()
+        }
+
+        match self {
+            Self::JavaBean => {
+                seed(&mut defaults.get.prefix, "get");
+                seed(&mut defaults.get_bool.prefix, "is");
+                seed(&mut defaults.get_mut.prefix, "get");
+            }
+            Self::RustApi => {}
+            Self::CStyle => {
+                if is_named(&defaults.all) {
+                    return;
+                }
+
+                let template = NameTemplate(
+                    vec![
+                        TemplateSegment::Struct,
+                        TemplateSegment::Literal("_".into()),
+                        TemplateSegment::Field,
+                        TemplateSegment::Literal("_".into()),
+                        TemplateSegment::Kind,
+                    ],
+                    Span::call_site(),
+                );
+
+                // Accessor types with their own naming keep it; `get_bool` falls back to `get`'s
+                if !is_named(&defaults.get_bool) && !is_named(&defaults.get) {
+                    defaults.get_bool.name_template = Some(template.clone());
+                }
+                for kind in defaults.kinds_mut() {
+                    if !is_named(kind) {
+                        kind.name_template = Some(template.clone());
+                    }
+                }
+            }
+        }
+    }
+}
+
 macro_rules! parse_opt {
     ($($for: ty),+ $(,)?) => {
         $(
@@ -283,4 +1019,11 @@ macro_rules! parse_opt {
     };
 }
 
-parse_opt!(SkippableIdent, DerefKind);
+parse_opt!(
+    SkippableIdent,
+    DerefKind,
+    Returns,
+    DefaultValue,
+    Conversion,
+    Converter,
+);
@@ -1,21 +1,382 @@
-use super::FieldOptions;
+use super::case::{is_ident, to_ident};
+use super::{
+    types, ContainerDefaults, ContainerOptions, FieldOptions, VariationDefaults, WhenRule,
+};
 use macroific::prelude::*;
-use proc_macro2::Ident;
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, ToTokens};
+use syn::ext::IdentExt;
+use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
-use syn::{Attribute, Field};
+use syn::{Attribute, Field, Fields, Index, Member, Token, Variant};
 
 #[cfg_attr(feature = "_debug", derive(Debug))]
 pub struct ParsedField {
     pub comments: Vec<Attribute>,
     pub opts: FieldOptions,
-    pub ident: Ident,
+    pub access: FieldAccess,
     pub ty: syn::Type,
 }
 
-impl TryFrom<Field> for ParsedField {
+/// How to reach the field from `self`
+#[cfg_attr(feature = "_debug", derive(Debug))]
+pub struct FieldAccess {
+    pub member: Member,
+
+    /// Enum variants containing the field; `None` on structs
+    pub variants: Option<Vec<Ident>>,
+
+    /// `false` if the field is only present in some of the enum's variants
+    pub exhaustive: bool,
+
+    /// Whether the field belongs to a union, making reads unsafe
+    pub union: bool,
+}
+
+/// How `self` is taken by the accessor
+#[derive(Copy, Clone)]
+pub enum Receiver {
+    /// `&self` or `&mut self`
+    Ref,
+
+    /// `self`, with the field getting moved out
+    Owned,
+
+    /// `mut self`, with the field getting modified in place
+    OwnedMut,
+}
+
+impl ParsedField {
+    /// Whether the field gets `bool` accessors; detected from the type unless set explicitly
+    pub fn is_bool(&self) -> bool {
+        self.opts.bool.unwrap_or_else(|| types::is_bool(&self.ty))
+    }
+
+    /// Reject `bool` accessors explicitly requested on non-`bool` fields
+    pub fn check_bool_accessors(&self) -> syn::Result<()> {
+        if self.is_bool() {
+            return Ok(());
+        }
+
+        let requested = [&self.opts.toggle, &self.opts.enable, &self.opts.disable]
+            .into_iter()
+            .any(move |opts| opts.as_ref().is_some_and(|opts| opts.skip != Some(true)));
+        if requested {
+            Err(syn::Error::new_spanned(
+                &self.ty,
+                "`toggle`, `enable` & `disable` only apply to `bool` fields; set `bool` on the field to override type detection",
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Whether the `option` accessor family applies to the field
+    pub fn is_option(&self, container: &ContainerOptions) -> bool {
+        self.opts.option.unwrap_or(container.option) && self.option_inner().is_some()
+    }
+
+    /// Reject `option` explicitly set on a non-`Option` field
+    pub fn check_option_accessors(&self) -> syn::Result<()> {
+        if self.opts.option == Some(true) && self.option_inner().is_none() {
+            Err(syn::Error::new_spanned(
+                &self.ty,
+                "`option` only applies to `Option` fields",
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Reject `conv` & `map = path` set on the field's non-`get` accessor types
+    pub fn check_getter_conversions(&self) -> syn::Result<()> {
+        let opts = &self.opts;
+        let others = [
+            &opts.get_mut,
+            &opts.set,
+            &opts.with,
+            &opts.replace,
+            &opts.take,
+            &opts.swap,
+            &opts.update,
+            &opts.map,
+            &opts.is_some,
+            &opts.clear,
+            &opts.or,
+            &opts.or_insert_with,
+            &opts.toggle,
+            &opts.enable,
+            &opts.disable,
+        ];
+
+        for opts in others.into_iter().flatten() {
+            if let Some(ref path) = opts.map {
+                return Err(syn::Error::new_spanned(
+                    path,
+                    "`map = path` only applies to `get`",
+                ));
+            }
+            if opts.conv.is_some() {
+                return Err(syn::Error::new_spanned(
+                    &self.ty,
+                    "`conv` only applies to `get`",
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// `get` defaults inferred from the field's type by `smart`, unless the field, the container's
+    /// `get` defaults or a matching `when` rule pick their own return style
+    pub fn smart_get_defaults(
+        &self,
+        container: &ContainerOptions,
+        when_rules: &[WhenRule],
+    ) -> Option<VariationDefaults> {
+        let smart = container.smart.as_ref()?;
+        let explicit_field =
+            [&self.opts.all, &self.opts.get]
+                .into_iter()
+                .flatten()
+                .any(move |opts| {
+                    [opts.cp, opts.owned, opts.as_ref, opts.deref, opts.as_deref]
+                        .into_iter()
+                        .any(move |flag| flag.is_some())
+                        || opts.ty.is_some()
+                        || opts.default.is_some()
+                        || opts.conv.is_some()
+                        || opts.map.is_some()
+                });
+
+        let is_bool = self.is_bool();
+        let explicit_defaults = move |defaults: &ContainerDefaults| {
+            let get_bool = if is_bool {
+                Some(&defaults.get_bool)
+            } else {
+                None
+            };
+            [&defaults.all, &defaults.get]
+                .into_iter()
+                .chain(get_bool)
+                .any(VariationDefaults::sets_return_style)
+        };
+        let explicit_container = explicit_defaults(&container.defaults)
+            || when_rules
+                .iter()
+                .filter(|rule| types::matches_pattern(&rule.ty, &self.ty))
+                .any(move |rule| explicit_defaults(&rule.defaults));
+
+        if explicit_field || explicit_container {
+            None
+        } else {
+            smart.infer(&self.ty)
+        }
+    }
+
+    /// The `T` of an `Option<T>` or `*mut Option<T>` field
+    fn option_inner(&self) -> Option<&syn::Type> {
+        match self.ty {
+            syn::Type::Ptr(ref ptr) => types::option_inner(&ptr.elem),
+            ref ty => types::option_inner(ty),
+        }
+    }
+
+    /// The name accessor names get built from
+    pub fn name_base(&self, container: &ContainerOptions) -> syn::Result<Member> {
+        let all_name = self
+            .opts
+            .all
+            .as_ref()
+            .and_then(move |all| all.name.as_ref());
+        if let Some(name) = self.opts.name.as_ref().or(all_name) {
+            return Ok(Member::Named(name.clone()));
+        }
+
+        let Member::Named(ref ident) = self.access.member else {
+            return Ok(self.access.member.clone());
+        };
+
+        let strip_prefix = self
+            .opts
+            .strip_prefix
+            .as_ref()
+            .or(container.strip_prefix.as_ref());
+        let rename_all = self.opts.rename_all.or(container.rename_all);
+        if strip_prefix.is_none() && rename_all.is_none() {
+            return Ok(self.access.member.clone());
+        }
+
+        let mut name = ident.unraw().to_string();
+        if let Some(strip_prefix) = strip_prefix {
+            name = strip_prefix.apply(&name).to_owned();
+        }
+        if let Some(rename_all) = rename_all {
+            name = rename_all.apply(&name);
+        }
+
+        if is_ident(&name) {
+            Ok(Member::Named(to_ident(&name, ident.span())))
+        } else {
+            Err(syn::Error::new_spanned(
+                ident,
+                format!("`{ident}` becomes `{name}` after `strip_prefix` & `rename_all`, which isn't a valid identifier; set `name` on the field"),
+            ))
+        }
+    }
+
+    /// Collect the named fields present in the variants of an enum with the same type
+    pub fn from_variants(variants: Punctuated<Variant, Token![,]>) -> syn::Result<Vec<Self>> {
+        let variant_count = variants.len();
+        let mut groups: Vec<(Ident, Vec<(Ident, Field)>)> = Vec::new();
+
+        for variant in variants {
+            let Fields::Named(fields) = variant.fields else {
+                continue;
+            };
+
+            for field in fields.named {
+                let ident = field.ident.clone().unwrap();
+                let occurrence = (variant.ident.clone(), field);
+
+                if let Some((_, occurrences)) = groups.iter_mut().find(|(i, _)| *i == ident) {
+                    occurrences.push(occurrence);
+                } else {
+                    groups.push((ident, vec![occurrence]));
+                }
+            }
+        }
+
+        let mut out = Vec::with_capacity(groups.len());
+        for (ident, occurrences) in groups {
+            if let Some(field) = Self::from_occurrences(&ident, occurrences, variant_count)? {
+                out.push(field);
+            }
+        }
+
+        Ok(out)
+    }
+
+    fn from_occurrences(
+        ident: &Ident,
+        occurrences: Vec<(Ident, Field)>,
+        variant_count: usize,
+    ) -> syn::Result<Option<Self>> {
+        let mut with_opts = occurrences.iter().filter(move |(_, f)| {
+            f.attrs
+                .iter()
+                .any(move |a| a.path().is_ident(super::ATTR_NAME))
+        });
+        let opts_source = with_opts.next().map(move |(v, _)| v.clone());
+        if let Some((_, dupe)) = with_opts.next() {
+            return Err(syn::Error::new_spanned(
+                dupe,
+                format!("Options for `{ident}` may only be set in one variant"),
+            ));
+        }
+
+        let ty_str = occurrences[0].1.ty.to_token_stream().to_string();
+        let same_ty = occurrences
+            .iter()
+            .all(move |(_, f)| f.ty.to_token_stream().to_string() == ty_str);
+
+        if !same_ty {
+            return match opts_source {
+                Some(_) => Err(syn::Error::new_spanned(
+                    ident,
+                    format!("`{ident}` must have the same type in every variant"),
+                )),
+                None => Ok(None),
+            };
+        }
+        let exhaustive = occurrences.len() == variant_count;
+
+        let source_idx = match opts_source {
+            Some(ref source) => occurrences
+                .iter()
+                .position(move |(v, _)| v == source)
+                .unwrap(),
+            None => 0,
+        };
+        let (variants, mut fields): (Vec<_>, Vec<_>) = occurrences.into_iter().unzip();
+
+        let mut field = Self::try_from((0, fields.swap_remove(source_idx)))?;
+        if field.comments.is_empty() {
+            field.comments = fields
+                .into_iter()
+                .map(move |f| f.attrs)
+                .find(move |attrs| attrs.iter().any(move |a| a.path().is_ident("doc")))
+                .unwrap_or_default()
+                .into_iter()
+                .filter(move |a| a.path().is_ident("doc"))
+                .collect();
+        }
+        field.access.variants = Some(variants);
+        field.access.exhaustive = exhaustive;
+
+        Ok(Some(field))
+    }
+}
+
+impl FieldAccess {
+    /// Render `body` with its argument resolving to a place expression for the field. `fallback` is
+    /// rendered for enum variants not containing the field.
+    #[inline]
+    pub fn render<F>(&self, receiver: Receiver, body: F, fallback: TokenStream) -> TokenStream
+    where
+        F: FnOnce(TokenStream) -> TokenStream,
+    {
+        self.render_on(&Ident::create("self"), receiver, body, fallback)
+    }
+
+    /// [`render`](Self::render) for a receiver other than `self`
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn render_on<F>(
+        &self,
+        receiver_ident: &Ident,
+        receiver: Receiver,
+        body: F,
+        fallback: TokenStream,
+    ) -> TokenStream
+    where
+        F: FnOnce(TokenStream) -> TokenStream,
+    {
+        let member = &self.member;
+        let Some(ref variants) = self.variants else {
+            return body(quote!(#receiver_ident.#member));
+        };
+
+        // Keep bindings of nested renders on different receivers apart
+        let binding = if receiver_ident == "self" {
+            Ident::create("__accessory_field")
+        } else {
+            Ident::create(&format!("__accessory_{receiver_ident}_field"))
+        };
+        let (scrutinee, place) = match receiver {
+            Receiver::Ref => (quote!(#receiver_ident), quote!((*#binding))),
+            Receiver::Owned => (quote!(#receiver_ident), binding.to_token_stream()),
+            Receiver::OwnedMut => (quote!(&mut #receiver_ident), quote!((*#binding))),
+        };
+        let body = body(place);
+        let fallback = if self.exhaustive {
+            None
+        } else {
+            Some(quote!(_ => #fallback,))
+        };
+
+        quote! {
+            match #scrutinee {
+                #(Self::#variants { #member: #binding, .. })|* => #body,
+                #fallback
+            }
+        }
+    }
+}
+
+impl TryFrom<(usize, Field)> for ParsedField {
     type Error = syn::Error;
 
-    fn try_from(field: Field) -> syn::Result<Self> {
+    fn try_from((index, field): (usize, Field)) -> syn::Result<Self> {
         let mut comments = Vec::new();
         let span = field.span();
 
@@ -32,11 +393,23 @@ impl TryFrom<Field> for ParsedField {
         });
 
         let opts = FieldOptions::from_iter(span, attr_iter)?;
+        let member = match field.ident {
+            Some(ident) => Member::Named(ident),
+            None => Member::Unnamed(Index {
+                index: u32::try_from(index).map_err(move |e| syn::Error::new(span, e))?,
+                span,
+            }),
+        };
 
         Ok(Self {
             comments,
             opts,
-            ident: field.ident.unwrap(),
+            access: FieldAccess {
+                member,
+                variants: None,
+                exhaustive: true,
+                union: false,
+            },
             ty: field.ty,
         })
     }
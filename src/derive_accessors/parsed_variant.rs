@@ -0,0 +1,92 @@
+use super::VariantOptions;
+use macroific::prelude::*;
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::spanned::Spanned;
+use syn::{Attribute, Fields, Member, Type, Variant};
+
+use super::case::to_snake_case;
+
+#[cfg_attr(feature = "_debug", derive(Debug))]
+pub struct ParsedVariant {
+    pub comments: Vec<Attribute>,
+    pub opts: VariantOptions,
+    pub shape: VariantShape,
+}
+
+#[cfg_attr(feature = "_debug", derive(Debug))]
+pub struct VariantShape {
+    pub ident: Ident,
+    pub fields: Fields,
+}
+
+impl ParsedVariant {
+    pub fn name_base(&self) -> Member {
+        let ident = &self.shape.ident;
+        let all_name = self
+            .opts
+            .all
+            .as_ref()
+            .and_then(move |all| all.name.as_ref());
+        Member::Named(match self.opts.name.as_ref().or(all_name) {
+            Some(name) => name.clone(),
+            None => Ident::new(&to_snake_case(&ident.to_string()), ident.span()),
+        })
+    }
+}
+
+impl VariantShape {
+    /// A pattern matching this variant & binding all its fields
+    pub fn pattern(&self) -> (TokenStream, Vec<Ident>) {
+        let ident = &self.ident;
+        let bindings: Vec<Ident> = (0..self.fields.len())
+            .map(move |idx| format_ident!("__accessory_{idx}"))
+            .collect();
+
+        let pattern = match self.fields {
+            Fields::Named(ref fields) => {
+                let members = fields.named.iter().map(move |f| &f.ident);
+                quote!(Self::#ident { #(#members: #bindings),* })
+            }
+            Fields::Unnamed(_) => quote!(Self::#ident(#(#bindings),*)),
+            Fields::Unit => quote!(Self::#ident),
+        };
+
+        (pattern, bindings)
+    }
+
+    pub fn field_types(&self) -> impl Iterator<Item = &Type> {
+        self.fields.iter().map(move |f| &f.ty)
+    }
+}
+
+impl TryFrom<&Variant> for ParsedVariant {
+    type Error = syn::Error;
+
+    fn try_from(variant: &Variant) -> syn::Result<Self> {
+        let mut comments = Vec::new();
+
+        let attr_iter = variant.attrs.iter().filter_map(|a| {
+            let ident = a.path().get_ident()?.to_string();
+            match ident.as_str() {
+                "doc" => {
+                    comments.push(a.clone());
+                    None
+                }
+                super::ATTR_NAME => Some(a.clone()),
+                _ => None,
+            }
+        });
+
+        let opts = VariantOptions::from_iter(variant.span(), attr_iter)?;
+
+        Ok(Self {
+            comments,
+            opts,
+            shape: VariantShape {
+                ident: variant.ident.clone(),
+                fields: variant.fields.clone(),
+            },
+        })
+    }
+}
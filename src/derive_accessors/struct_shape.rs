@@ -0,0 +1,150 @@
+use proc_macro2::{Ident, TokenStream, TokenTree};
+use quote::{format_ident, quote, ToTokens};
+use syn::{Error, GenericParam, Generics, Member, Type};
+
+/// What's needed to rebuild a struct with one of its fields replaced
+#[cfg_attr(feature = "_debug", derive(Debug))]
+pub struct StructShape {
+    pub ident: Ident,
+    pub generics: Generics,
+    pub fields: Vec<(Member, Type)>,
+}
+
+/// A setter changing one of the struct's type parameters
+pub struct Retyped {
+    /// The new type parameter's ident
+    pub ident: Ident,
+
+    /// The setter's own generic param, e.g. `World2: Clone`
+    pub param: TokenStream,
+
+    /// `where` predicates the struct declares for the original param, rewritten for the new one
+    pub predicates: Vec<TokenStream>,
+
+    /// The struct's type with the param replaced, e.g. `Hello<World2>`
+    pub output: TokenStream,
+
+    /// The rebuilt struct with `new_value` in place of the field
+    pub value: TokenStream,
+}
+
+impl StructShape {
+    pub fn retype(&self, member: &Member, ty: &Type) -> syn::Result<Retyped> {
+        let param = self.type_param(ty).ok_or_else(move || {
+            Error::new_spanned(
+                ty,
+                "`retype` requires the field's type to be one of the struct's type parameters",
+            )
+        })?;
+
+        for (other_member, other_ty) in &self.fields {
+            if other_member != member && contains_ident(other_ty.to_token_stream(), &param.ident) {
+                return Err(Error::new_spanned(
+                    other_ty,
+                    format!(
+                        "`retype` can't change `{}` as other fields use it too",
+                        param.ident
+                    ),
+                ));
+            }
+        }
+
+        let new_ident = self.unique_ident(&param.ident);
+        let replace = |tokens: TokenStream| replace_ident(tokens, &param.ident, &new_ident);
+
+        let colon = &param.colon_token;
+        let bounds = replace(param.bounds.to_token_stream());
+        let predicates = self
+            .generics
+            .where_clause
+            .iter()
+            .flat_map(move |clause| &clause.predicates)
+            .map(ToTokens::to_token_stream)
+            .filter(|predicate| contains_ident(predicate.clone(), &param.ident))
+            .map(replace)
+            .collect();
+
+        let args = self.generics.params.iter().map(|generic| match generic {
+            GenericParam::Type(tp) if tp.ident == param.ident => new_ident.to_token_stream(),
+            GenericParam::Type(tp) => tp.ident.to_token_stream(),
+            GenericParam::Lifetime(lt) => lt.lifetime.to_token_stream(),
+            GenericParam::Const(c) => c.ident.to_token_stream(),
+        });
+        let ident = &self.ident;
+        let output = quote!(#ident<#(#args),*>);
+
+        let values = self.fields.iter().map(move |(other_member, _)| {
+            if other_member == member {
+                quote!(#other_member: new_value)
+            } else {
+                quote!(#other_member: self.#other_member)
+            }
+        });
+        let value = quote!(#ident { #(#values),* });
+
+        Ok(Retyped {
+            param: quote!(#new_ident #colon #bounds),
+            ident: new_ident,
+            predicates,
+            output,
+            value,
+        })
+    }
+
+    fn type_param(&self, ty: &Type) -> Option<&syn::TypeParam> {
+        let Type::Path(ty) = ty else {
+            return None;
+        };
+        let ident = ty.qself.is_none().then(|| ty.path.get_ident())??;
+
+        self.generics
+            .type_params()
+            .find(move |tp| tp.ident == *ident)
+    }
+
+    /// `World` → `World2`, or `World3` if that's taken etc.
+    fn unique_ident(&self, base: &Ident) -> Ident {
+        let taken = |ident: &Ident| {
+            self.generics
+                .params
+                .iter()
+                .any(move |generic| match generic {
+                    GenericParam::Type(tp) => tp.ident == *ident,
+                    GenericParam::Const(c) => c.ident == *ident,
+                    GenericParam::Lifetime(_) => false,
+                })
+        };
+
+        // One of these is guaranteed to be free
+        (2..=self.generics.params.len() + 2)
+            .map(move |idx| format_ident!("{}{}", base, idx))
+            .find(move |ident| !taken(ident))
+            .unwrap()
+    }
+}
+
+fn contains_ident(tokens: TokenStream, ident: &Ident) -> bool {
+    tokens.into_iter().any(move |tt| match tt {
+        TokenTree::Ident(ref i) => i == ident,
+        TokenTree::Group(group) => contains_ident(group.stream(), ident),
+        TokenTree::Punct(_) | TokenTree::Literal(_) => false,
+    })
+}
+
+fn replace_ident(tokens: TokenStream, from: &Ident, to: &Ident) -> TokenStream {
+    tokens
+        .into_iter()
+        .map(move |tt| match tt {
+            TokenTree::Ident(ref i) if i == from => TokenTree::Ident(to.clone()),
+            TokenTree::Group(group) => {
+                let mut out = proc_macro2::Group::new(
+                    group.delimiter(),
+                    replace_ident(group.stream(), from, to),
+                );
+                out.set_span(group.span());
+                TokenTree::Group(out)
+            }
+            other => other,
+        })
+        .collect()
+}
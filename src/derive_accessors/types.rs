@@ -0,0 +1,267 @@
+use proc_macro2::{TokenStream, TokenTree};
+use quote::ToTokens;
+use syn::punctuated::Punctuated;
+use syn::{
+    Expr, GenericArgument, GenericParam, Generics, Lifetime, Path, PathArguments, Token, Type,
+};
+
+const INTEGERS: &[&str] = &[
+    "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128", "usize",
+];
+
+/// Check whether the type is spelled `bool`, `core::primitive::bool` or `std::primitive::bool`
+pub fn is_bool(ty: &Type) -> bool {
+    match ty {
+        Type::Path(ty) if ty.qself.is_none() => is_primitive_path(&ty.path, "bool"),
+        Type::Group(ty) => is_bool(&ty.elem),
+        Type::Paren(ty) => is_bool(&ty.elem),
+        _ => false,
+    }
+}
+
+/// Get `T` out of a type spelled `Option<T>`, `core::option::Option<T>` or `std::option::Option<T>`
+pub fn option_inner(ty: &Type) -> Option<&Type> {
+    let path = match ty {
+        Type::Path(ty) if ty.qself.is_none() => &ty.path,
+        Type::Group(ty) => return option_inner(&ty.elem),
+        Type::Paren(ty) => return option_inner(&ty.elem),
+        _ => return None,
+    };
+
+    let idents: Vec<String> = path
+        .segments
+        .iter()
+        .map(move |seg| seg.ident.to_string())
+        .collect();
+    let is_option = match idents.as_slice() {
+        [ident] => path.leading_colon.is_none() && ident == "Option",
+        [krate, module, ident] => {
+            (krate == "core" || krate == "std") && module == "option" && ident == "Option"
+        }
+        _ => false,
+    };
+    if !is_option
+        || path
+            .segments
+            .iter()
+            .rev()
+            .skip(1)
+            .any(|seg| !seg.arguments.is_none())
+    {
+        return None;
+    }
+
+    let PathArguments::AngleBracketed(ref args) = path.segments.last()?.arguments else {
+        return None;
+    };
+    match args.args.first() {
+        Some(GenericArgument::Type(inner)) if args.args.len() == 1 => Some(inner),
+        _ => None,
+    }
+}
+
+/// Check whether the type is one `smart` treats as `Copy`: primitives, shared references &
+/// `NonZero*` integers
+pub fn is_smart_copy(ty: &Type) -> bool {
+    match ty {
+        Type::Path(ty) if ty.qself.is_none() => {
+            ["bool", "char", "f32", "f64"]
+                .iter()
+                .chain(INTEGERS)
+                .any(|name| is_primitive_path(&ty.path, name))
+                || std_type_name(&ty.path, &["num"]).is_some_and(|name| {
+                    name.strip_prefix("NonZero").is_some_and(move |int| {
+                        int.is_empty() || INTEGERS.iter().any(move |i| i.eq_ignore_ascii_case(int))
+                    })
+                })
+        }
+        Type::Reference(ty) => ty.mutability.is_none(),
+        Type::Group(ty) => is_smart_copy(&ty.elem),
+        Type::Paren(ty) => is_smart_copy(&ty.elem),
+        _ => false,
+    }
+}
+
+/// Check whether the type is one `smart` gives deref getters: `String`, `Vec`, `Box` or `PathBuf`
+pub fn is_smart_deref(ty: &Type) -> bool {
+    match ty {
+        Type::Path(ty) if ty.qself.is_none() => {
+            std_type_name(&ty.path, &["string", "vec", "boxed", "path"])
+                .is_some_and(|name| matches!(name.as_str(), "String" | "Vec" | "Box" | "PathBuf"))
+        }
+        Type::Group(ty) => is_smart_deref(&ty.elem),
+        Type::Paren(ty) => is_smart_deref(&ty.elem),
+        _ => false,
+    }
+}
+
+/// Check whether the type is spelled the same as one in the list
+pub fn is_listed(list: &Punctuated<Type, Token![,]>, ty: &Type) -> bool {
+    list.iter().any(move |listed| tokens_eq(listed, ty))
+}
+
+/// Check whether the type matches a pattern where `_` matches any type. Paths in the pattern
+/// match the end of the type's path, e.g. `Option<_>` matches `core::option::Option<u8>`.
+pub fn matches_pattern(pattern: &Type, ty: &Type) -> bool {
+    match (pattern, ty) {
+        (Type::Infer(_), _) => true,
+        (Type::Group(pattern), ty) => matches_pattern(&pattern.elem, ty),
+        (Type::Paren(pattern), ty) => matches_pattern(&pattern.elem, ty),
+        (pattern, Type::Group(ty)) => matches_pattern(pattern, &ty.elem),
+        (pattern, Type::Paren(ty)) => matches_pattern(pattern, &ty.elem),
+        (Type::Path(pattern), Type::Path(ty)) if pattern.qself.is_none() && ty.qself.is_none() => {
+            path_matches(&pattern.path, &ty.path)
+        }
+        (Type::Reference(pattern), Type::Reference(ty)) => {
+            pattern.mutability.is_some() == ty.mutability.is_some()
+                && lifetime_matches(pattern.lifetime.as_ref(), ty.lifetime.as_ref())
+                && matches_pattern(&pattern.elem, &ty.elem)
+        }
+        (Type::Ptr(pattern), Type::Ptr(ty)) => {
+            pattern.mutability.is_some() == ty.mutability.is_some()
+                && matches_pattern(&pattern.elem, &ty.elem)
+        }
+        (Type::Slice(pattern), Type::Slice(ty)) => matches_pattern(&pattern.elem, &ty.elem),
+        (Type::Array(pattern), Type::Array(ty)) => {
+            (matches!(pattern.len, Expr::Infer(_)) || tokens_eq(&pattern.len, &ty.len))
+                && matches_pattern(&pattern.elem, &ty.elem)
+        }
+        (Type::Tuple(pattern), Type::Tuple(ty)) => {
+            pattern.elems.len() == ty.elems.len()
+                && pattern
+                    .elems
+                    .iter()
+                    .zip(&ty.elems)
+                    .all(move |(pattern, ty)| matches_pattern(pattern, ty))
+        }
+        (pattern, ty) => tokens_eq(pattern, ty),
+    }
+}
+
+fn path_matches(pattern: &Path, path: &Path) -> bool {
+    let Some(offset) = path.segments.len().checked_sub(pattern.segments.len()) else {
+        return false;
+    };
+    if pattern.leading_colon.is_some() && (offset != 0 || path.leading_colon.is_none()) {
+        return false;
+    }
+
+    pattern
+        .segments
+        .iter()
+        .zip(path.segments.iter().skip(offset))
+        .all(move |(pattern, seg)| {
+            pattern.ident == seg.ident
+                && match (&pattern.arguments, &seg.arguments) {
+                    (PathArguments::None, PathArguments::None) => true,
+                    (
+                        PathArguments::AngleBracketed(pattern),
+                        PathArguments::AngleBracketed(args),
+                    ) => {
+                        pattern.args.len() == args.args.len()
+                            && pattern
+                                .args
+                                .iter()
+                                .zip(&args.args)
+                                .all(move |pair| match pair {
+                                    (GenericArgument::Type(pattern), GenericArgument::Type(ty)) => {
+                                        matches_pattern(pattern, ty)
+                                    }
+                                    (
+                                        GenericArgument::Lifetime(pattern),
+                                        GenericArgument::Lifetime(lt),
+                                    ) => lifetime_matches(Some(pattern), Some(lt)),
+                                    (pattern, arg) => tokens_eq(pattern, arg),
+                                })
+                    }
+                    (pattern, args) => tokens_eq(pattern, args),
+                }
+        })
+}
+
+/// Check whether the type mentions `Self` or any of the generic parameters, which items nested in
+/// the accessor can't use
+pub fn uses_generics(ty: &Type, generics: &Generics) -> bool {
+    fn walk(tokens: TokenStream, names: &[String]) -> bool {
+        tokens.into_iter().any(move |tree| match tree {
+            TokenTree::Ident(ident) => ident == "Self" || names.iter().any(|name| ident == name),
+            TokenTree::Group(group) => walk(group.stream(), names),
+            TokenTree::Punct(_) | TokenTree::Literal(_) => false,
+        })
+    }
+
+    let names: Vec<String> = generics
+        .params
+        .iter()
+        .map(move |param| match param {
+            GenericParam::Lifetime(param) => param.lifetime.ident.to_string(),
+            GenericParam::Type(param) => param.ident.to_string(),
+            GenericParam::Const(param) => param.ident.to_string(),
+        })
+        .collect();
+
+    walk(ty.to_token_stream(), &names)
+}
+
+/// An omitted or `'_` lifetime in the pattern matches any lifetime
+fn lifetime_matches(pattern: Option<&Lifetime>, lifetime: Option<&Lifetime>) -> bool {
+    match pattern {
+        Some(pattern) if pattern.ident != "_" => lifetime == Some(pattern),
+        _ => true,
+    }
+}
+
+fn tokens_eq(a: &impl ToTokens, b: &impl ToTokens) -> bool {
+    a.to_token_stream().to_string() == b.to_token_stream().to_string()
+}
+
+/// The type's name if it's spelled `Name` or `{core,alloc,std}::{module}::Name`
+fn std_type_name(path: &Path, modules: &[&str]) -> Option<String> {
+    if path
+        .segments
+        .iter()
+        .rev()
+        .skip(1)
+        .any(|seg| !seg.arguments.is_none())
+    {
+        return None;
+    }
+
+    let idents: Vec<String> = path
+        .segments
+        .iter()
+        .map(move |seg| seg.ident.to_string())
+        .collect();
+    match idents.as_slice() {
+        [ident] if path.leading_colon.is_none() => Some(ident.clone()),
+        [krate, module, ident]
+            if matches!(krate.as_str(), "core" | "alloc" | "std")
+                && modules.contains(&module.as_str()) =>
+        {
+            Some(ident.clone())
+        }
+        _ => None,
+    }
+}
+
+fn is_primitive_path(path: &Path, name: &str) -> bool {
+    let segments: Vec<String> = path
+        .segments
+        .iter()
+        .map(move |seg| {
+            if seg.arguments.is_none() {
+                seg.ident.to_string()
+            } else {
+                String::new()
+            }
+        })
+        .collect();
+
+    match segments.as_slice() {
+        [ident] => path.leading_colon.is_none() && ident == name,
+        [krate, module, ident] => {
+            (krate == "core" || krate == "std") && module == "primitive" && ident == name
+        }
+        _ => false,
+    }
+}
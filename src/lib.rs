@@ -361,7 +361,8 @@
 //!
 //! <details><summary>Calling as_ref on Option fields</summary>
 //!
-//! Combine the `as_ref` & `ty` options to `Option<&T>` instead of `&Option<T>`. Has no effect on `set`.
+//! `as_ref` returns `Option<&T>` instead of `&Option<T>`; `ty` can be set explicitly if the field's type
+//! isn't spelled `Option<T>`. Has no effect on `set`.
 //!
 //! ```
 //! # use accessory::Accessors;
@@ -397,6 +398,1370 @@
 //!
 //! </details>
 //!
+//! <details><summary>Tuple structs</summary>
+//!
+//! Positional fields get named after their index unless given a `name`.
+//!
+//! ```
+//! #[derive(accessory::Accessors, Default)]
+//! #[access(get, set)]
+//! struct Meters(#[access(name = value, get(cp))] f64, u8);
+//!
+//! let mut m = Meters::default();
+//! m.set_value(1.5).set_1(3);
+//!
+//! assert_eq!(m.value(), 1.5);
+//! assert_eq!(*m._1(), 3);
+//! ```
+//!
+//! ### Generated output
+//!
+#![cfg_attr(doctest, doc = " ````no_test")]
+//! ```
+//! impl Meters {
+//!     #[inline]
+//!     pub fn value(&self) -> f64 { self.0 }
+//!
+//!     #[inline]
+//!     pub fn set_value(&mut self, new_value: f64) -> &mut Self {
+//!         self.0 = new_value;
+//!         self
+//!     }
+//!
+//!     #[inline]
+//!     pub fn _1(&self) -> &u8 { &self.1 }
+//!
+//!     #[inline]
+//!     pub fn set_1(&mut self, new_value: u8) -> &mut Self {
+//!         self.1 = new_value;
+//!         self
+//!     }
+//! }
+//! ````
+//!
+//! </details>
+//!
+//! <details><summary>Enums</summary>
+//!
+//! Named fields present in every variant with the same type get accessors that match over all the
+//! variants. Options can be set on the field in any one of the variants.
+//!
+//! ```
+//! #[derive(accessory::Accessors)]
+//! #[access(get, set)]
+//! enum Event {
+//!     Created {
+//!         #[access(get(cp))]
+//!         id: u64,
+//!         name: String,
+//!     },
+//!     Deleted {
+//!         id: u64,
+//!     },
+//! }
+//!
+//! let mut event = Event::Deleted { id: 1 };
+//! event.set_id(2);
+//! assert_eq!(event.id(), 2);
+//! ```
+//!
+//! ### Generated output
+//!
+#![cfg_attr(doctest, doc = " ````no_test")]
+//! ```
+//! impl Event {
+//!     #[inline]
+//!     pub fn id(&self) -> u64 {
+//!         match self {
+//!             Self::Created { id: __accessory_field, .. } | Self::Deleted { id: __accessory_field, .. } => (*__accessory_field),
+//!         }
+//!     }
+//!
+//!     #[inline]
+//!     pub fn set_id(&mut self, new_value: u64) -> &mut Self {
+//!         match self {
+//!             Self::Created { id: __accessory_field, .. } | Self::Deleted { id: __accessory_field, .. } => (*__accessory_field) = new_value,
+//!         };
+//!         self
+//!     }
+//! }
+//! ````
+//!
+//! </details>
+//!
+//! <details><summary>Enum fields missing from some variants</summary>
+//!
+//! Fields only present in some of the variants get wrapped in an `Option` when getting and a
+//! `Result` when setting. The setter hands the new value back if the active variant doesn't have the
+//! field (or `(self, new_value)` if `owned`).
+//!
+//! ```
+//! #[derive(accessory::Accessors, Debug)]
+//! #[access(get, get_mut, set)]
+//! enum Shape {
+//!     Circle {
+//!         #[access(get(cp))]
+//!         radius: f64,
+//!     },
+//!     Square {
+//!         side: f64,
+//!     },
+//! }
+//!
+//! let mut shape = Shape::Circle { radius: 1.0 };
+//! assert_eq!(shape.radius(), Some(1.0));
+//! assert_eq!(shape.side(), None);
+//!
+//! *shape.radius_mut().unwrap() = 2.0;
+//! assert!(shape.set_radius(3.0).is_ok());
+//! assert_eq!(shape.set_side(4.0).unwrap_err(), 4.0);
+//! assert_eq!(shape.radius(), Some(3.0));
+//! ```
+//!
+//! ### Generated output
+//!
+#![cfg_attr(doctest, doc = " ````no_test")]
+//! ```
+//! impl Shape {
+//!     #[inline]
+//!     pub fn radius(&self) -> Option<f64> {
+//!         match self {
+//!             Self::Circle { radius: __accessory_field, .. } => Some((*__accessory_field)),
+//!             _ => None,
+//!         }
+//!     }
+//!
+//!     // radius_mut, side & side_mut follow the same pattern
+//!
+//!     #[inline]
+//!     pub fn set_radius(&mut self, new_value: f64) -> Result<&mut Self, f64> {
+//!         match self {
+//!             Self::Circle { radius: __accessory_field, .. } => (*__accessory_field) = new_value,
+//!             _ => return Err(new_value),
+//!         };
+//!         Ok(self)
+//!     }
+//! }
+//! ````
+//!
+//! </details>
+//!
+//! <details><summary>Enum variant accessors</summary>
+//!
+//! `is_variant`, `as_variant`, `as_variant_mut` & `into_variant` derive accessors for the enum's
+//! variants rather than its fields. Variants with more than one field get their fields returned as a
+//! tuple; unit variants only get `is_variant`.
+//!
+//! ```
+//! #[derive(accessory::Accessors, Debug, PartialEq)]
+//! #[access(is_variant, as_variant, as_variant_mut, into_variant, defaults(is_variant(const_fn)))]
+//! enum Message {
+//!     Quit,
+//!     Write(String),
+//!     #[access(name = rgb, into_variant(skip))]
+//!     ChangeColor(u8, u8, u8),
+//! }
+//!
+//! let mut msg = Message::Write("Hello".into());
+//! assert!(msg.is_write());
+//! assert!(!msg.is_quit());
+//!
+//! msg.as_write_mut().unwrap().push_str(", world!");
+//! assert_eq!(msg.as_write().map(String::as_str), Some("Hello, world!"));
+//! assert_eq!(msg.as_rgb(), None);
+//!
+//! assert_eq!(Message::ChangeColor(1, 2, 3).as_rgb(), Some((&1, &2, &3)));
+//! assert_eq!(Message::Quit.into_write(), Err(Message::Quit));
+//! assert_eq!(msg.into_write(), Ok(String::from("Hello, world!")));
+//! ```
+//!
+//! ### Generated output
+//!
+#![cfg_attr(doctest, doc = " ````no_test")]
+//! ```
+//! impl Message {
+//!     #[inline]
+//!     pub const fn is_quit(&self) -> bool { matches!(self, Self::Quit { .. }) }
+//!
+//!     #[inline]
+//!     pub const fn is_write(&self) -> bool { matches!(self, Self::Write { .. }) }
+//!
+//!     #[inline]
+//!     pub fn as_write(&self) -> Option<&String> {
+//!         match self {
+//!             Self::Write(__accessory_0) => Some(__accessory_0),
+//!             _ => None,
+//!         }
+//!     }
+//!
+//!     #[inline]
+//!     pub fn as_write_mut(&mut self) -> Option<&mut String> {
+//!         match self {
+//!             Self::Write(__accessory_0) => Some(__accessory_0),
+//!             _ => None,
+//!         }
+//!     }
+//!
+//!     #[inline]
+//!     pub fn into_write(self) -> Result<String, Self> {
+//!         match self {
+//!             Self::Write(__accessory_0) => Ok(__accessory_0),
+//!             _ => Err(self),
+//!         }
+//!     }
+//!
+//!     #[inline]
+//!     pub const fn is_rgb(&self) -> bool { matches!(self, Self::ChangeColor { .. }) }
+//!
+//!     #[inline]
+//!     pub fn as_rgb(&self) -> Option<(&u8, &u8, &u8)> {
+//!         match self {
+//!             Self::ChangeColor(__accessory_0, __accessory_1, __accessory_2) => {
+//!                 Some((__accessory_0, __accessory_1, __accessory_2))
+//!             }
+//!             _ => None,
+//!         }
+//!     }
+//!
+//!     #[inline]
+//!     pub fn as_rgb_mut(&mut self) -> Option<(&mut u8, &mut u8, &mut u8)> {
+//!         match self {
+//!             Self::ChangeColor(__accessory_0, __accessory_1, __accessory_2) => {
+//!                 Some((__accessory_0, __accessory_1, __accessory_2))
+//!             }
+//!             _ => None,
+//!         }
+//!     }
+//! }
+//! ````
+//!
+//! </details>
+//!
+//! <details><summary>Unions</summary>
+//!
+//! Reading a union field is unsafe, so `get` & `get_mut` accessors on unions are generated as
+//! `unsafe fn`s with a `# Safety` doc section. Setters stay safe.
+//!
+//! ```
+//! #[derive(accessory::Accessors)]
+//! #[access(get, set, defaults(get(cp)))]
+//! #[repr(C)]
+//! union Bits {
+//!     /// Integer view
+//!     int: u32,
+//!     float: f32,
+//! }
+//!
+//! let mut bits = Bits { int: 0 };
+//! bits.set_float(1.0);
+//! assert_eq!(unsafe { bits.int() }, 0x3f80_0000);
+//! ```
+//!
+//! ### Generated output
+//!
+#![cfg_attr(doctest, doc = " ````no_test")]
+//! ```
+//! impl Bits {
+//!     /// Integer view
+//!     ///
+//!     /// # Safety
+//!     ///
+//!     /// `int` must be the union's active field
+//!     #[inline]
+//!     pub unsafe fn int(&self) -> u32 { unsafe { self.int } }
+//!
+//!     /// Integer view
+//!     #[inline]
+//!     pub fn set_int(&mut self, new_value: u32) -> &mut Self {
+//!         self.int = new_value;
+//!         self
+//!     }
+//!
+//!     /// # Safety
+//!     ///
+//!     /// `float` must be the union's active field
+//!     #[inline]
+//!     pub unsafe fn float(&self) -> f32 { unsafe { self.float } }
+//!
+//!     #[inline]
+//!     pub fn set_float(&mut self, new_value: f32) -> &mut Self {
+//!         self.float = new_value;
+//!         self
+//!     }
+//! }
+//! ````
+//!
+//! </details>
+//!
+//! <details><summary>Overriding names</summary>
+//!
+//! `name` inside `get`, `get_mut` or `set` sets the accessor's full name while `all(name = ...)` sets
+//! the base name prefixes & suffixes get applied to.
+//!
+//! ```
+//! #[derive(Default, accessory::Accessors)]
+//! #[access(get, get_mut, set)]
+//! struct Entry {
+//!     #[access(all(name = distinguished_name), get_mut(name = dn_mut))]
+//!     raw_dn: String,
+//!
+//!     #[access(get(name = display_name), set(name = rename_to))]
+//!     name: String,
+//! }
+//!
+//! let mut entry = Entry::default();
+//! entry.set_distinguished_name("cn=admin".into()).rename_to("Admin".into());
+//! entry.dn_mut().push_str(",dc=example");
+//!
+//! assert_eq!(entry.distinguished_name(), "cn=admin,dc=example");
+//! assert_eq!(entry.display_name(), "Admin");
+//! assert_eq!(entry.name_mut(), "Admin");
+//! ```
+//!
+//! </details>
+//!
+//! <details><summary>Deprecated aliases</summary>
+//!
+//! Keep old accessor names around after a rename with `alias`. The aliases share the accessor's
+//! visibility, const-ness & bounds and forward to it.
+//!
+//! ```
+//! #[derive(Default, accessory::Accessors)]
+//! struct Settings {
+//!     #[access(get(cp, const_fn, alias(timeout, get_timeout)), set(alias(set_timeout)))]
+//!     timeout_ms: u64,
+//! }
+//!
+//! let mut settings = Settings::default();
+//! #[allow(deprecated)]
+//! {
+//!     settings.set_timeout(100);
+//!     assert_eq!(settings.timeout(), 100);
+//!     assert_eq!(settings.get_timeout(), 100);
+//! }
+//! assert_eq!(settings.timeout_ms(), 100);
+//! ```
+//!
+//! ### Generated output
+//!
+#![cfg_attr(doctest, doc = " ````no_test")]
+//! ```
+//! impl Settings {
+//!     #[inline]
+//!     #[must_use]
+//!     pub const fn timeout_ms(&self) -> u64 { self.timeout_ms }
+//!
+//!     /// Deprecated alias of [`timeout_ms`](Self::timeout_ms)
+//!     #[deprecated(note = "use `timeout_ms`")]
+//!     #[inline]
+//!     #[must_use]
+//!     pub const fn timeout(&self) -> u64 { Self::timeout_ms(self) }
+//!
+//!     /// Deprecated alias of [`timeout_ms`](Self::timeout_ms)
+//!     #[deprecated(note = "use `timeout_ms`")]
+//!     #[inline]
+//!     #[must_use]
+//!     pub const fn get_timeout(&self) -> u64 { Self::timeout_ms(self) }
+//!
+//!     #[inline]
+//!     pub fn set_timeout_ms(&mut self, new_value: u64) -> &mut Self {
+//!         self.timeout_ms = new_value;
+//!         self
+//!     }
+//!
+//!     /// Deprecated alias of [`set_timeout_ms`](Self::set_timeout_ms)
+//!     #[deprecated(note = "use `set_timeout_ms`")]
+//!     #[inline]
+//!     pub fn set_timeout(&mut self, new_value: u64) -> &mut Self {
+//!         Self::set_timeout_ms(self, new_value)
+//!     }
+//! }
+//! ````
+//!
+//! </details>
+//!
+//! <details><summary>Renaming fields</summary>
+//!
+//! `strip_prefix` & `rename_all` get applied to field names before accessor prefixes & suffixes. They
+//! don't affect explicitly set names.
+//!
+//! ```
+//! #[derive(Default, accessory::Accessors)]
+//! #[access(get, set, rename_all = "snake_case", strip_prefix("dw", "lpsz", "cb"))]
+//! #[allow(non_snake_case)]
+//! struct WinStruct {
+//!     dwSize: u32,
+//!     lpszName: String,
+//!     #[access(strip_prefix = "cbMax")]
+//!     cbMaxData: u32,
+//! }
+//!
+//! let mut s = WinStruct::default();
+//! s.set_size(1).set_name("foo".into()).set_data(2);
+//!
+//! assert_eq!(*s.size(), 1);
+//! assert_eq!(s.name(), "foo");
+//! assert_eq!(*s.data(), 2);
+//! ```
+//!
+//! </details>
+//!
+//! <details><summary>Raw identifiers</summary>
+//!
+//! Raw identifiers get stripped when building accessor names and only get re-added if the final name
+//! is a keyword.
+//!
+//! ```
+//! #[derive(Default, accessory::Accessors)]
+//! #[access(get, get_mut, set)]
+//! struct Packet {
+//!     r#type: u8,
+//! }
+//!
+//! let mut packet = Packet::default();
+//! packet.set_type(1);
+//! *packet.type_mut() += 1;
+//! assert_eq!(*packet.r#type(), 2);
+//! ```
+//!
+//! </details>
+//!
+//! <details><summary>Name templates</summary>
+//!
+//! ```
+//! #[derive(Default, accessory::Accessors)]
+//! #[access(get, set, defaults(get(name_template = "get_{field}_ref")))]
+//! struct Config {
+//!     port: u16,
+//!
+//!     #[access(set(name_template = "{struct}_{kind}_{field}"))]
+//!     host: String,
+//! }
+//!
+//! let mut config = Config::default();
+//! config.set_port(80).config_set_host("localhost".into());
+//!
+//! assert_eq!(*config.get_port_ref(), 80);
+//! assert_eq!(config.get_host_ref(), "localhost");
+//! ```
+//!
+//! A `prefix` or `suffix` set on the field takes precedence over a template inherited from the
+//! container.
+//!
+//! </details>
+//!
+//! <details><summary>Naming presets</summary>
+//!
+//! ```
+//! #[derive(Default, accessory::Accessors)]
+//! #[access(get, get_mut, set, preset = java_bean, defaults(get(cp)))]
+//! struct Bean {
+//!     size: u32,
+//!     visible: bool,
+//!
+//!     #[access(get(prefix = fetch))]
+//!     colour: u8,
+//! }
+//!
+//! let mut bean = Bean::default();
+//! bean.set_size(1).set_visible(true);
+//! *bean.get_size_mut() += 1;
+//!
+//! assert_eq!(bean.get_size(), 2);
+//! assert!(bean.is_visible());
+//! assert_eq!(bean.fetch_colour(), 0);
+//!
+//! #[derive(Default, accessory::Accessors)]
+//! #[access(get, set, preset = c_style)]
+//! struct Point {
+//!     x: i32,
+//! }
+//!
+//! let mut point = Point::default();
+//! point.point_x_set(5);
+//! assert_eq!(*point.point_x_get(), 5);
+//! ```
+//!
+//! </details>
+//!
+//! <details><summary>Bool fields</summary>
+//!
+//! Getters of `bool` fields can be named differently from other getters through `defaults(get_bool(...))`,
+//! e.g. `defaults(get_bool(prefix = is))` or `preset = java_bean` for an `is_` prefix. `toggle`, `enable` &
+//! `disable` generate accessors flipping or setting the flag. Type detection only recognises `bool` as spelled;
+//! set `bool` or `bool = false` on a field to override it. Fields already named `is_*` or `has_*` don't get a
+//! second `is_` prefix.
+//!
+//! ```
+//! #[derive(Default, accessory::Accessors)]
+//! #[access(get, toggle, enable, disable, defaults(get(cp), get_bool(prefix = is)))]
+//! struct Flags {
+//!     visible: bool,
+//!     is_ready: bool,
+//!
+//!     #[access(get(prefix = has))]
+//!     children: bool,
+//!
+//!     #[access(bool = false)]
+//!     raw: bool,
+//! }
+//!
+//! let mut flags = Flags::default();
+//! flags.toggle_visible().enable_children();
+//!
+//! assert!(flags.is_visible());
+//! assert!(!flags.is_ready());
+//! assert!(flags.has_children());
+//! assert!(!flags.raw());
+//! ```
+//!
+//! ### Generated output
+//!
+#![cfg_attr(doctest, doc = " ````no_test")]
+//! ```
+//! #[inline]
+//! #[must_use]
+//! pub fn is_visible(&self) -> bool {
+//!     self.visible
+//! }
+//!
+//! #[inline]
+//! pub fn toggle_visible(&mut self) -> &mut Self {
+//!     {
+//!         self.visible = !self.visible;
+//!     }
+//!     self
+//! }
+//!
+//! #[inline]
+//! pub fn enable_visible(&mut self) -> &mut Self {
+//!     {
+//!         self.visible = true;
+//!     }
+//!     self
+//! }
+//! ````
+//!
+//! </details>
+//!
+//! <details><summary>Builder-style setters</summary>
+//!
+//! `with` accessors always take `self` & return `Self`, so they can be derived next to `&mut self`
+//! setters without duplicating the field's config.
+//!
+//! ```
+//! #[derive(Default, accessory::Accessors)]
+//! #[access(get, set, with)]
+//! struct Request {
+//!     url: String,
+//!     #[access(with(prefix = and))]
+//!     retries: u8,
+//! }
+//!
+//! let mut req = Request::default()
+//!     .with_url("https://example.com".into())
+//!     .and_retries(3);
+//! req.set_retries(5);
+//!
+//! assert_eq!(req.url(), "https://example.com");
+//! assert_eq!(*req.retries(), 5);
+//! ```
+//!
+//! ### Generated output
+//!
+#![cfg_attr(doctest, doc = " ````no_test")]
+//! ```
+//! #[inline]
+//! pub fn with_url(mut self, new_value: String) -> Self {
+//!     self.url = new_value;
+//!     self
+//! }
+//! ````
+//!
+//! </details>
+//!
+//! <details><summary>Into setters</summary>
+//!
+//! `into` makes setters accept anything convertible into the field's type, or into `ty` if it's set.
+//!
+//! ```
+//! # use std::path::PathBuf;
+//! #[derive(Default, accessory::Accessors)]
+//! #[access(set, defaults(set(into)))]
+//! struct Config {
+//!     name: String,
+//!     path: PathBuf,
+//! }
+//!
+//! let mut config = Config::default();
+//! config.set_name("foo").set_path("/tmp");
+//!
+//! assert_eq!(config.name, "foo");
+//! ```
+//!
+//! ### Generated output
+//!
+#![cfg_attr(doctest, doc = " ````no_test")]
+//! ```
+//! #[inline]
+//! pub fn set_name(&mut self, new_value: impl Into<String>) -> &mut Self {
+//!     let new_value: String = Into::into(new_value);
+//!     self.name = new_value;
+//!     self
+//! }
+//! ````
+//!
+//! </details>
+//!
+//! <details><summary>Setter return styles</summary>
+//!
+//! `returns` picks what a setter returns: `ref` (`&mut Self`, the default), `owned` (`Self`, the
+//! default with `owned`), `unit` or `old` - the previous value. `ref` is a keyword, so it has to be
+//! written as `returns(ref)`. Only `set` reads it, so it's not inherited from `all`.
+//!
+//! ```
+//! #[derive(Default, accessory::Accessors)]
+//! #[access(set)]
+//! struct Counter {
+//!     #[access(set(returns = old))]
+//!     count: u32,
+//!
+//!     #[access(set(returns = unit))]
+//!     label: String,
+//! }
+//!
+//! let mut counter = Counter::default();
+//! counter.set_label("clicks".into());
+//!
+//! assert_eq!(counter.set_count(5), 0);
+//! assert_eq!(counter.set_count(6), 5);
+//! ```
+//!
+//! ### Generated output
+//!
+#![cfg_attr(doctest, doc = " ````no_test")]
+//! ```
+//! #[inline]
+//! pub fn set_count(&mut self, new_value: u32) -> u32 {
+//!     core::mem::replace(&mut self.count, new_value)
+//! }
+//!
+//! #[inline]
+//! pub fn set_label(&mut self, new_value: String) -> () {
+//!     self.label = new_value;
+//! }
+//! ````
+//!
+//! </details>
+//!
+//! <details><summary>Exchange accessors</summary>
+//!
+//! `replace` returns the previous value, `take` leaves [`Default::default()`] behind & `swap` swaps
+//! the field with the same field of another instance. `take` gets a `Default` bound on the field's type.
+//!
+//! ```
+//! #[derive(Default, accessory::Accessors)]
+//! #[access(replace, take, swap)]
+//! struct Buffer<T> {
+//!     data: Vec<T>,
+//! }
+//!
+//! let mut buf = Buffer { data: vec![1, 2] };
+//! let mut other = Buffer { data: vec![3] };
+//!
+//! assert_eq!(buf.replace_data(vec![4]), [1, 2]);
+//! buf.swap_data(&mut other);
+//! assert_eq!(buf.take_data(), [3]);
+//! assert_eq!(other.data, [4]);
+//! assert!(buf.data.is_empty());
+//! ```
+//!
+//! ### Generated output
+//!
+#![cfg_attr(doctest, doc = " ````no_test")]
+//! ```
+//! #[inline]
+//! pub fn replace_data(&mut self, new_value: Vec<T>) -> Vec<T> {
+//!     core::mem::replace(&mut self.data, new_value)
+//! }
+//!
+//! #[inline]
+//! pub fn take_data(&mut self) -> Vec<T> where Vec<T>: Default {
+//!     core::mem::take(&mut self.data)
+//! }
+//!
+//! #[inline]
+//! pub fn swap_data(&mut self, other: &mut Self) -> &mut Self {
+//!     {
+//!         core::mem::swap(&mut self.data, &mut other.data);
+//!     }
+//!     self
+//! }
+//! ````
+//!
+//! </details>
+//!
+//! <details><summary>Closure accessors</summary>
+//!
+//! `update` passes a mutable reference to the field to a closure without breaking method chains;
+//! `owned` makes it take & return `self`. `map` replaces the field with the closure's output & is only
+//! available on struct fields that can be moved out of `self`; enabled on the container, it skips enum &
+//! union fields. Neither can be a const fn, so both ignore `const_fn`, as does `take`.
+//!
+//! ```
+//! #[derive(Default, accessory::Accessors)]
+//! #[access(update, map)]
+//! struct Stats {
+//!     hits: u32,
+//!     names: Vec<String>,
+//! }
+//!
+//! let mut stats = Stats::default();
+//! stats
+//!     .update_hits(|hits| *hits += 1)
+//!     .update_names(|names| names.push("foo".into()));
+//!
+//! let stats = stats.map_hits(|hits| hits * 10);
+//!
+//! assert_eq!(stats.hits, 10);
+//! assert_eq!(stats.names, ["foo"]);
+//! ```
+//!
+//! ### Generated output
+//!
+#![cfg_attr(doctest, doc = " ````no_test")]
+//! ```
+//! #[inline]
+//! pub fn update_hits(&mut self, f: impl FnOnce(&mut u32)) -> &mut Self {
+//!     {
+//!         f(&mut self.hits);
+//!     }
+//!     self
+//! }
+//!
+//! #[inline]
+//! pub fn map_hits(mut self, f: impl FnOnce(u32) -> u32) -> Self {
+//!     self.hits = f(self.hits);
+//!     self
+//! }
+//! ````
+//!
+//! </details>
+//!
+//! <details><summary>Type-changing setters</summary>
+//!
+//! `retype` on a `set` or `with` accessor of a field whose type is one of the struct's type parameters
+//! makes the setter take `self` & return the struct with that parameter swapped out. Bounds the struct
+//! declares on the parameter get carried over.
+//!
+//! ```
+//! #[derive(accessory::Accessors)]
+//! #[access(with)]
+//! struct Pipeline<Input: Clone, Output> {
+//!     #[access(with(retype))]
+//!     input: Input,
+//!     output: Output,
+//! }
+//!
+//! let pipeline = Pipeline { input: 1u8, output: "foo" };
+//! let pipeline: Pipeline<String, &str> = pipeline.with_input(String::from("bar"));
+//!
+//! assert_eq!(pipeline.input, "bar");
+//! ```
+//!
+//! ### Generated output
+//!
+#![cfg_attr(doctest, doc = " ````no_test")]
+//! ```
+//! #[inline]
+//! pub fn with_input<Input2: Clone>(self, new_value: Input2) -> Pipeline<Input2, Output> {
+//!     Pipeline {
+//!         input: new_value,
+//!         output: self.output
+//!     }
+//! }
+//! ````
+//!
+//! </details>
+//!
+//! <details><summary>Option accessors</summary>
+//!
+//! `option` enables a family of accessors for `Option` fields: `is_*_some`, `clear_*`, `take_*`, `*_or`
+//! & `*_or_insert_with`. It also makes getters return `Option<&T>` & setters take `T` & wrap it in
+//! `Some` by default; those come from the `as_ref` & `some` options which can be overridden. Each
+//! accessor type can also be enabled or configured individually on a field.
+//!
+//! ```
+//! #[derive(Default, accessory::Accessors)]
+//! #[access(get, set, option)]
+//! struct User {
+//!     nickname: Option<String>,
+//!
+//!     #[access(or(cp))]
+//!     age: Option<u8>,
+//! }
+//!
+//! let mut user = User::default();
+//! assert!(!user.is_nickname_some());
+//! assert_eq!(user.age_or(18), 18);
+//!
+//! user.set_nickname("foo".into()).set_age(30);
+//! assert_eq!(user.nickname(), Some(&String::from("foo")));
+//! assert_eq!(user.age_or(18), 30);
+//!
+//! user.clear_age();
+//! assert_eq!(user.take_nickname().as_deref(), Some("foo"));
+//! assert_eq!(user.nickname_or_insert_with(|| "bar".into()), "bar");
+//! ```
+//!
+//! ### Generated output
+//!
+#![cfg_attr(doctest, doc = " ````no_test")]
+//! ```
+//! #[inline]
+//! #[must_use]
+//! pub fn nickname(&self) -> Option<&String> {
+//!     self.nickname.as_ref()
+//! }
+//!
+//! #[inline]
+//! pub fn set_nickname(&mut self, new_value: String) -> &mut Self {
+//!     self.nickname = Some(new_value);
+//!     self
+//! }
+//!
+//! #[inline]
+//! #[must_use]
+//! pub fn is_nickname_some(&self) -> bool {
+//!     self.nickname.is_some()
+//! }
+//!
+//! #[inline]
+//! pub fn clear_nickname(&mut self) -> &mut Self {
+//!     {
+//!         self.nickname = None;
+//!     }
+//!     self
+//! }
+//!
+//! #[inline]
+//! pub fn take_nickname(&mut self) -> Option<String> where Option<String>: Default {
+//!     core::mem::take(&mut self.nickname)
+//! }
+//!
+//! #[inline]
+//! #[must_use]
+//! pub fn nickname_or<'__accessory>(&'__accessory self, default: &'__accessory String) -> &'__accessory String {
+//!     self.nickname.as_ref().unwrap_or(default)
+//! }
+//!
+//! #[inline]
+//! pub fn nickname_or_insert_with(&mut self, f: impl FnOnce() -> String) -> &mut String {
+//!     self.nickname.get_or_insert_with(f)
+//! }
+//! ````
+//!
+//! </details>
+//!
+//! <details><summary>Default-value getters</summary>
+//!
+//! `get(default = expr)` unwraps an `Option` field in its getter, falling back to `expr` when it's `None`. The getter
+//! returns `T` with `cp` or `owned` & `&T` otherwise; in the latter case `expr` must be a constant expression as it
+//! gets promoted to a `'static` reference. A bare `default` falls back to [`Default::default()`] & thus requires
+//! `cp` or `owned`. If `T` uses the struct's generic parameters, the getter can't return a reference to `expr`
+//! & needs `cp` or `owned` too.
+//!
+//! ```
+//! const DEFAULT_PORT: u16 = 8080;
+//!
+//! #[derive(Default, accessory::Accessors)]
+//! struct Config {
+//!     #[access(get(cp, default = DEFAULT_PORT))]
+//!     port: Option<u16>,
+//!
+//!     #[access(get(default = String::new()))]
+//!     host: Option<String>,
+//!
+//!     #[access(get(cp, default))]
+//!     retries: Option<u8>,
+//! }
+//!
+//! let mut config = Config::default();
+//! assert_eq!(config.port(), 8080);
+//! assert_eq!(config.host(), "");
+//! assert_eq!(config.retries(), 0);
+//!
+//! config.port = Some(80);
+//! config.host = Some("localhost".into());
+//! assert_eq!(config.port(), 80);
+//! assert_eq!(config.host(), "localhost");
+//! ```
+//!
+//! ```
+//! #[derive(Default, accessory::Accessors)]
+//! struct Slot<'a, T: Copy> {
+//!     #[access(get(cp, default = None))]
+//!     value: Option<Option<T>>,
+//!
+//!     #[access(get(cp, default = "none"))]
+//!     label: Option<&'a str>,
+//!
+//!     #[access(get(default = String::new()))]
+//!     note: Option<String>,
+//! }
+//!
+//! let slot = Slot::<u8>::default();
+//! assert_eq!(slot.value(), None);
+//! assert_eq!(slot.label(), "none");
+//! assert_eq!(slot.note(), "");
+//! ```
+//!
+//! ### Generated output
+//!
+#![cfg_attr(doctest, doc = " ````no_test")]
+//! ```
+//! #[inline]
+//! #[must_use]
+//! pub fn port(&self) -> u16 {
+//!     match self.port {
+//!         Some(value) => value,
+//!         None => DEFAULT_PORT,
+//!     }
+//! }
+//!
+//! #[inline]
+//! #[must_use]
+//! pub fn host(&self) -> &String {
+//!     match &self.host {
+//!         Some(value) => value,
+//!         None => {
+//!             const DEFAULT: &String = &(String::new());
+//!             DEFAULT
+//!         }
+//!     }
+//! }
+//!
+//! #[inline]
+//! #[must_use]
+//! pub fn retries(&self) -> u8 where u8: Default {
+//!     match self.retries {
+//!         Some(value) => value,
+//!         None => Default::default(),
+//!     }
+//! }
+//! ````
+//!
+//! </details>
+//!
+//! <details><summary>Deref getters</summary>
+//!
+//! `deref` makes a `get` accessor return `&<T as Deref>::Target` & a `get_mut` accessor return
+//! `&mut <T as Deref>::Target`, e.g. `&str` for `String` or `&[T]` for `Vec<T>`. `as_deref` does the same for
+//! `Option` fields.
+//!
+//! ```
+//! #[derive(Default, accessory::Accessors)]
+//! #[access(get, get_mut, defaults(get(deref), get_mut(deref)))]
+//! struct Post {
+//!     title: String,
+//!     tags: Vec<String>,
+//!
+//!     #[access(get(as_deref), get_mut(as_deref))]
+//!     summary: Option<Box<str>>,
+//! }
+//!
+//! let mut post = Post::default();
+//! post.title = "hello".into();
+//! post.title_mut().make_ascii_uppercase();
+//!
+//! let title: &str = post.title();
+//! let tags: &[String] = post.tags();
+//! assert_eq!(title, "HELLO");
+//! assert!(tags.is_empty());
+//! assert_eq!(post.summary(), None);
+//! ```
+//!
+//! ### Generated output
+//!
+#![cfg_attr(doctest, doc = " ````no_test")]
+//! ```
+//! #[inline]
+//! #[must_use]
+//! pub fn title(&self) -> &<String as core::ops::Deref>::Target {
+//!     &*self.title
+//! }
+//!
+//! #[inline]
+//! #[must_use]
+//! pub fn title_mut(&mut self) -> &mut <String as core::ops::Deref>::Target {
+//!     &mut *self.title
+//! }
+//!
+//! #[inline]
+//! #[must_use]
+//! pub fn summary(&self) -> Option<&<Box<str> as core::ops::Deref>::Target> {
+//!     self.summary.as_deref()
+//! }
+//!
+//! #[inline]
+//! #[must_use]
+//! pub fn summary_mut(&mut self) -> Option<&mut <Box<str> as core::ops::Deref>::Target> {
+//!     self.summary.as_deref_mut()
+//! }
+//! ````
+//!
+//! </details>
+//!
+//! <details><summary>Type-driven defaults</summary>
+//!
+//! `smart` picks `get` options based on each field's type: `Option`s get `as_ref`, primitives, shared references
+//! & `NonZero*` integers get `cp` and `String`, `Vec`, `Box` & `PathBuf` get `deref`. More types can be added to
+//! each list & a field setting its own return style opts out of the inference, as do all fields if the
+//! container's `get` or `all` defaults set one.
+//!
+//! ```
+//! #[derive(Clone, Copy, Default, PartialEq, Debug)]
+//! struct UserId(u64);
+//!
+//! #[derive(Default, accessory::Accessors)]
+//! #[access(get, smart(copy(UserId)))]
+//! struct User {
+//!     id: UserId,
+//!     age: u8,
+//!     name: String,
+//!     roles: Vec<String>,
+//!     email: Option<String>,
+//!
+//!     #[access(get(as_ref = false))]
+//!     nickname: Option<String>,
+//! }
+//!
+//! let user = User::default();
+//! let id: UserId = user.id();
+//! let age: u8 = user.age();
+//! let name: &str = user.name();
+//! let roles: &[String] = user.roles();
+//! let email: Option<&String> = user.email();
+//! let nickname: &Option<String> = user.nickname();
+//! ```
+//!
+//! ```
+//! #[derive(Default, accessory::Accessors)]
+//! #[access(get, smart, defaults(get(owned)))]
+//! struct Config {
+//!     name: String,
+//!     port: u16,
+//! }
+//!
+//! let name: String = Config::default().name();
+//! let port: u16 = Config::default().port();
+//! ```
+//!
+//! ### Generated output
+//!
+#![cfg_attr(doctest, doc = " ````no_test")]
+//! ```
+//! #[inline]
+//! #[must_use]
+//! pub fn id(&self) -> UserId {
+//!     self.id
+//! }
+//!
+//! #[inline]
+//! #[must_use]
+//! pub fn age(&self) -> u8 {
+//!     self.age
+//! }
+//!
+//! #[inline]
+//! #[must_use]
+//! pub fn name(&self) -> &<String as core::ops::Deref>::Target {
+//!     &*self.name
+//! }
+//!
+//! #[inline]
+//! #[must_use]
+//! pub fn roles(&self) -> &<Vec<String> as core::ops::Deref>::Target {
+//!     &*self.roles
+//! }
+//!
+//! #[inline]
+//! #[must_use]
+//! pub fn email(&self) -> Option<&String> {
+//!     self.email.as_ref()
+//! }
+//!
+//! #[inline]
+//! #[must_use]
+//! pub fn nickname(&self) -> &Option<String> {
+//!     &self.nickname
+//! }
+//! ````
+//!
+//! </details>
+//!
+//! <details><summary>Type-matched defaults</summary>
+//!
+//! `when` rules set accessor defaults for fields whose type matches a pattern. `_` matches any type & a path in
+//! the pattern matches the end of the field type's path, so `Vec<_>` matches `std::vec::Vec<u8>` too. A field
+//! picks up the options of every rule it matches, with earlier rules taking priority over later ones, field
+//! options taking priority over rules and rules taking priority over `defaults`. `when` can be repeated. A rule
+//! picking a getter's return style turns off `smart` inference for the fields it matches.
+//!
+//! ```
+//! #[derive(Default, accessory::Accessors)]
+//! #[access(get, set, when(ty = u8, get(cp)))]
+//! #[access(when(ty = Option<_>, get(as_ref), set(some)), when(ty = Vec<_>, get(deref), set(into)))]
+//! struct Config {
+//!     retries: u8,
+//!     name: Option<String>,
+//!     hosts: Vec<String>,
+//!
+//!     #[access(get(as_ref = false))]
+//!     tags: Option<Vec<String>>,
+//! }
+//!
+//! let mut config = Config::default();
+//! config.set_name("db".into()).set_hosts(["localhost".to_string()]);
+//!
+//! let retries: u8 = config.retries();
+//! let name: Option<&String> = config.name();
+//! let hosts: &[String] = config.hosts();
+//! let tags: &Option<Vec<String>> = config.tags();
+//! ```
+//!
+//! ```
+//! #[derive(Default, accessory::Accessors)]
+//! #[access(get, smart, when(ty = String, get(conv = clone)))]
+//! struct User {
+//!     name: String,
+//!     roles: Vec<String>,
+//! }
+//!
+//! let user = User::default();
+//! let name: String = user.name();
+//! let roles: &[String] = user.roles();
+//! ```
+//!
+//! ### Generated output
+//!
+#![cfg_attr(doctest, doc = " ````no_test")]
+//! ```
+//! #[inline]
+//! #[must_use]
+//! pub fn retries(&self) -> u8 {
+//!     self.retries
+//! }
+//!
+//! #[inline]
+//! #[must_use]
+//! pub fn name(&self) -> Option<&String> {
+//!     self.name.as_ref()
+//! }
+//!
+//! #[inline]
+//! pub fn set_name(&mut self, new_value: String) -> &mut Self {
+//!     self.name = Some(new_value);
+//!     self
+//! }
+//!
+//! #[inline]
+//! #[must_use]
+//! pub fn hosts(&self) -> &<Vec<String> as core::ops::Deref>::Target {
+//!     &*self.hosts
+//! }
+//!
+//! #[inline]
+//! pub fn set_hosts(&mut self, new_value: impl Into<Vec<String>>) -> &mut Self {
+//!     self.hosts = new_value.into();
+//!     self
+//! }
+//!
+//! #[inline]
+//! #[must_use]
+//! pub fn tags(&self) -> &Option<Vec<String>> {
+//!     &self.tags
+//! }
+//! ````
+//!
+//! </details>
+//!
+//! <details><summary>Getter conversions</summary>
+//!
+//! `conv` converts the value a getter returns: `clone` & `copied` work on plain fields & element-wise on the
+//! `Option<&T>` returned by `as_ref`, while `into` & `to_owned` need the return type to be set with `ty`. `map`
+//! passes the value through a function & needs `ty` as well. Both only apply to `get`, so other accessor types
+//! don't inherit them from `all`.
+//!
+//! ```
+//! use std::sync::Arc;
+//!
+//! #[derive(Default, Debug, PartialEq)]
+//! struct Settings {
+//!     verbose: bool,
+//! }
+//!
+//! fn char_count(text: &String) -> usize {
+//!     text.chars().count()
+//! }
+//!
+//! #[derive(Default, accessory::Accessors)]
+//! #[access(get)]
+//! struct App {
+//!     #[access(get(conv = clone))]
+//!     settings: Arc<Settings>,
+//!
+//!     #[access(get(as_ref, conv = copied))]
+//!     port: Option<u16>,
+//!
+//!     #[access(get(cp, conv = into, ty(u64)))]
+//!     workers: u32,
+//!
+//!     #[access(get(map = char_count, ty(usize)))]
+//!     title: String,
+//! }
+//!
+//! let app = App {
+//!     port: Some(80),
+//!     workers: 4,
+//!     title: "héllo".into(),
+//!     ..Default::default()
+//! };
+//!
+//! let settings: Arc<Settings> = app.settings();
+//! assert_eq!(app.port(), Some(80));
+//! assert_eq!(app.workers(), 4u64);
+//! assert_eq!(app.title(), 5);
+//! ```
+//!
+//! ### Generated output
+//!
+#![cfg_attr(doctest, doc = " ````no_test")]
+//! ```
+//! #[inline]
+//! #[must_use]
+//! pub fn settings(&self) -> Arc<Settings> {
+//!     Clone::clone(&self.settings)
+//! }
+//!
+//! #[inline]
+//! #[must_use]
+//! pub fn port(&self) -> Option<u16> {
+//!     (self.port.as_ref()).copied()
+//! }
+//!
+//! #[inline]
+//! #[must_use]
+//! pub fn workers(&self) -> u64 {
+//!     Into::into(self.workers)
+//! }
+//!
+//! #[inline]
+//! #[must_use]
+//! pub fn title(&self) -> usize {
+//!     char_count(&self.title)
+//! }
+//! ````
+//!
+//! </details>
+//!
+//! <details><summary>Setter conversions</summary>
+//!
+//! `from = path` makes a setter take the type set with `ty` & store the output of the function at `path`.
+//! `try_from = path` does the same for a function returning a [`Result`], with the setter returning a `Result`
+//! too; the error type is set with `error`. Bare `from` & `try_from` use [`From`] & [`TryFrom`] instead of a
+//! function. Setters taking `self`, like `with`, hand it back next to the error.
+//!
+//! ```
+//! use std::num::ParseIntError;
+//! use std::path::PathBuf;
+//!
+//! fn normalise(name: &str) -> String {
+//!     name.trim().to_lowercase()
+//! }
+//!
+//! fn parse_port(port: &str) -> Result<u16, ParseIntError> {
+//!     port.parse()
+//! }
+//!
+//! #[derive(Default, accessory::Accessors)]
+//! #[access(set)]
+//! struct Config {
+//!     #[access(set(from = normalise, ty(&str)))]
+//!     name: String,
+//!
+//!     #[access(set(try_from = parse_port, ty(&str), error(ParseIntError)))]
+//!     port: u16,
+//!
+//!     #[access(set(try_from, ty(u64)))]
+//!     workers: u8,
+//!
+//!     #[access(set(from, ty(&str)))]
+//!     root: PathBuf,
+//! }
+//!
+//! let mut config = Config::default();
+//! config.set_name(" Main ").set_root("/srv");
+//! config.set_port("8080")?.set_workers(4)?;
+//! assert!(config.set_port("eighty").is_err());
+//! assert!(config.set_workers(1000).is_err());
+//!
+//! assert_eq!(config.name, "main");
+//! assert_eq!(config.port, 8080);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! ```
+//! #[derive(Default, accessory::Accessors)]
+//! struct Pool {
+//!     #[access(with(try_from, ty(u64)))]
+//!     size: u8,
+//! }
+//!
+//! let Err((pool, _)) = Pool::default().with_size(1000) else {
+//!     unreachable!();
+//! };
+//! let Ok(pool) = pool.with_size(8) else {
+//!     unreachable!();
+//! };
+//! assert_eq!(pool.size, 8);
+//! ```
+//!
+//! ### Generated output
+//!
+#![cfg_attr(doctest, doc = " ````no_test")]
+//! ```
+//! #[inline]
+//! pub fn set_name(&mut self, new_value: &str) -> &mut Self {
+//!     let new_value: String = normalise(new_value);
+//!     self.name = new_value;
+//!     self
+//! }
+//!
+//! #[inline]
+//! pub fn set_port(&mut self, new_value: &str) -> Result<&mut Self, ParseIntError> {
+//!     let new_value: u16 = parse_port(new_value)?;
+//!     self.port = new_value;
+//!     Ok(self)
+//! }
+//!
+//! #[inline]
+//! pub fn set_workers(&mut self, new_value: u64) -> Result<&mut Self, <u8 as TryFrom<u64>>::Error> {
+//!     let new_value: u8 = TryFrom::try_from(new_value)?;
+//!     self.workers = new_value;
+//!     Ok(self)
+//! }
+//!
+//! #[inline]
+//! pub fn set_root(&mut self, new_value: &str) -> &mut Self {
+//!     let new_value: PathBuf = From::from(new_value);
+//!     self.root = new_value;
+//!     self
+//! }
+//! ````
+//!
+//! </details>
+//!
 
 #![deny(clippy::correctness, clippy::suspicious)]
 #![warn(clippy::complexity, clippy::perf, clippy::style, clippy::pedantic)]
@@ -421,16 +1786,31 @@ use quote::ToTokens;
 ///
 /// | Option | Description |
 /// | --- | --- |
-/// | `const_fn` | Make the accessor a const fn |
-/// | `owned` | Make the accessor take `self` instead of `&self`. Ignored on `get_mut` |
-/// | `cp` | Treat the accessor as a copy type. If not set, it will be treated as a reference. Ignored on `get_mut` |
-/// | `as_ref` | Call [`as_ref()`](AsRef::as_ref) on the field. Ignored on `set`. Combine with `ty` to return [`Option`] refs. |
+/// | `const_fn` | Make the accessor a const fn. Ignored by `replace`, `take`, `swap`, `update`, `map`, `clear`, `or`, `or_insert_with` & getters using `deref`, `as_deref`, `conv` or `map = path`, as well as setters using `into`, `from` or `try_from`. |
+/// | `owned` | Make the accessor take `self` instead of `&self`. Ignored on `get_mut` & always on for `with` |
+/// | `cp` | Treat the accessor as a copy type. If not set, it will be treated as a reference. Ignored on `get_mut` & `set` |
+/// | `as_ref` | Call [`as_ref()`](AsRef::as_ref) on the field. Ignored on `set`. Returns [`Option`] refs on `Option<T>` fields; combine with `ty` for other types. |
+/// | `deref` | Return `&<T as Deref>::Target` from a `get` or `&mut <T as Deref>::Target` from a `get_mut` accessor, e.g. `&str` for a `String` field |
+/// | `as_deref` | Like `deref`, but for [`Option`] fields: return `Option<&<T as Deref>::Target>` via [`Option::as_deref`] or [`Option::as_deref_mut`] |
+/// | `some` | Make the setter of an [`Option`] field take `T` & wrap it in `Some` |
+/// | `into` | Make the setter accept `impl Into<T>`. Only applies to `set`, `with` & `replace`, which then ignore `const_fn` |
+/// | `from = path` | Make the setter store `path(new_value)`, taking the argument type set with `ty`. A bare `from` uses [`From::from`]. Applies to `set`, `with` & `replace`. Not inherited. |
+/// | `try_from = path` | Like `from`, but `path` returns a [`Result`] & so does the setter, e.g. `Result<&mut Self, E>`, or `Result<Self, (Self, E)>` with `owned` & on `with`. A bare `try_from` uses [`TryFrom::try_from`]. Not supported on fields missing from some enum variants. Not inherited. |
+/// | `error(Type)` | The error type of `try_from = path`. Optional with a bare `try_from` & rejected without `try_from`. Not inherited. |
+/// | `retype` | Let a `set` or `with` accessor change the type of a field typed as one of the struct's type parameters, returning the rebuilt struct. Not inherited. |
+/// | `conv = kind` | Convert a `get` accessor's value: `clone`, `copied` (both element-wise on [`Option`] refs from `as_ref`), `into` or `to_owned`. `into` & `to_owned` need the return type set with `ty`. Only applies to `get`; other accessor types don't inherit it from `all`. |
+/// | `map = path` | Pass a `get` accessor's value, after `conv`, through the function at `path`. Needs the return type set with `ty`. Only applies to `get`; other accessor types don't inherit it from `all`. |
+/// | `default = expr` | `Option` fields only: make a `get` accessor return `T` (with `cp` or `owned`) or `&T` instead of an [`Option`], falling back to `expr`. A bare `default` falls back to [`Default::default()`] & requires `cp` or `owned`. Not inherited. |
+/// | `returns = style` | What a `set` accessor returns: `returns(ref)` for `&mut Self`, `owned` for `Self`, `unit` or `old` for the previous value. Defaults to `ref`, or `owned` with `owned`. Not inherited from `all`. |
 /// | `skip` | Skip this accessor |
 /// | `ptr_deref()` | Dereference this raw pointer when getting/setting it. Can be passed an empty arg `()` to automatically determine `&` or `&mut` referencing based on context or pass `(mut)` inside a `get` to force resolve to a mutable reference. See "Dereferencing raw pointers" example in [crate-level docs](crate) for more info. |
 /// | `vis(visibility)` | Set the visibility of the accessor. Defaults to public. |
-/// | `ty(type)` | Set the return type of the accessor. Defaults to the field type + a reference if applicable. |
+/// | `ty(type)` | Set the return type of the accessor. Defaults to the field type + a reference if applicable. Not supported on `take`; on setters it sets the argument type, with `replace` still returning the field type. |
+/// | `name = ident` | Set the full accessor name, ignoring prefixes & suffixes. Inside a field's or variant's `all`, sets the base name prefixes & suffixes get applied to instead. |
+/// | `alias(a, b)` | Generate `#[deprecated]` accessors with these names forwarding to this one. Not inherited from `all`. |
 /// | `prefix(prefix)` | [`Ident`](struct@syn::Ident): Add a prefix to the accessor name, [`""`](struct@syn::LitStr): remove the inherited prefix |
 /// | `suffix(suffix)` | [`Ident`](struct@syn::Ident): Add a suffix to the accessor name, [`""`](struct@syn::LitStr): remove the inherited suffix |
+/// | `name_template = "template"` | Build the accessor name from a template instead of the prefix & suffix. Accepts the `{field}`, `{kind}` (e.g. `get_mut`) & `{struct}` (the container's name in `snake_case`) placeholders. |
 /// | `bounds(A: B + C, D: E)` | Add this `where` clause to this accessor implementation |
 ///
 /// # Field Options
@@ -438,14 +1818,46 @@ use quote::ToTokens;
 /// | Option | Description |
 /// | --- | --- |
 /// | `skip` | Skip this field |
+/// | `name = ident` | Base name to build accessor names from. Defaults to the field's ident or, on tuple structs, its index, prefixed with `_` when the accessor would otherwise start with a digit |
+/// | `rename_all = "rule"` | Override the container's `rename_all` for this field |
+/// | `strip_prefix = "prefix"` | Override the container's `strip_prefix` for this field |
+/// | `bool` | Treat the field as a `bool` (or not, with `bool = false`) regardless of its type |
+/// | `option` | Override the container's `option` for this field |
 /// | `all(AccessorTypeOptions)` | Set options for all accessor types on this field |
 /// | `get(AccessorTypeOptions)` | Set options for the `get` accessor type on this field |
 /// | `get_mut(AccessorTypeOptions)` | Set options for the `get_mut` accessor type on this field |
 /// | `set(AccessorTypeOptions)` | Set options for the `set` accessor type on this field |
+/// | `with(AccessorTypeOptions)` | Set options for the `with` accessor type on this field |
+/// | `replace(AccessorTypeOptions)` | Set options for the `replace` accessor type on this field |
+/// | `take(AccessorTypeOptions)` | Set options for the `take` accessor type on this field |
+/// | `swap(AccessorTypeOptions)` | Set options for the `swap` accessor type on this field |
+/// | `update(AccessorTypeOptions)` | Set options for the `update` accessor type on this field |
+/// | `map(AccessorTypeOptions)` | Set options for the `map` accessor type on this field |
+/// | `is_some(AccessorTypeOptions)` | `Option` fields only: set options for the `is_some` accessor type on this field |
+/// | `clear(AccessorTypeOptions)` | `Option` fields only: set options for the `clear` accessor type on this field |
+/// | `or(AccessorTypeOptions)` | `Option` fields only: set options for the `or` accessor type on this field. Takes & returns `T` by value with `cp` or `owned`. |
+/// | `or_insert_with(AccessorTypeOptions)` | `Option` fields only: set options for the `or_insert_with` accessor type on this field |
+/// | `toggle(AccessorTypeOptions)` | `bool` fields only: set options for the `toggle` accessor type on this field |
+/// | `enable(AccessorTypeOptions)` | `bool` fields only: set options for the `enable` accessor type on this field |
+/// | `disable(AccessorTypeOptions)` | `bool` fields only: set options for the `disable` accessor type on this field |
 ///
-/// `get`, `set` and `get_mut` will just enable the accessor type with inherited options if set
+/// `get`, `set`, `with` and `get_mut` will just enable the accessor type with inherited options if set
 /// with no parameters
 ///
+/// # Variant Options
+///
+/// | Option | Description |
+/// | --- | --- |
+/// | `skip` | Skip this variant |
+/// | `name = ident` | Base name to build accessor names from. Defaults to the variant's ident in `snake_case` |
+/// | `all(AccessorTypeOptions)` | Set options for all variant accessor types on this variant |
+/// | `is_variant(AccessorTypeOptions)` | Set options for the `is_variant` accessor type on this variant |
+/// | `as_variant(AccessorTypeOptions)` | Set options for the `as_variant` accessor type on this variant |
+/// | `as_variant_mut(AccessorTypeOptions)` | Set options for the `as_variant_mut` accessor type on this variant |
+/// | `into_variant(AccessorTypeOptions)` | Set options for the `into_variant` accessor type on this variant |
+///
+/// Only `skip`, `const_fn`, `vis`, `prefix`, `suffix` & `bounds` apply to variant accessors.
+///
 /// # Container Options
 ///
 /// | Option | Description |
@@ -453,20 +1865,67 @@ use quote::ToTokens;
 /// | `get` | Derive a `get` accessor for each field |
 /// | `get_mut` | Derive a `get_mut` accessor for each field |
 /// | `set` | Derive a `set` accessor for each field |
+/// | `with` | Derive a `with_*` accessor taking & returning `self` for each field |
+/// | `replace` | Derive a `replace_*` accessor returning the previous value for each field |
+/// | `take` | Derive a `take_*` accessor replacing the value with its default for each field |
+/// | `swap` | Derive a `swap_*` accessor swapping the field with another instance's for each field |
+/// | `update` | Derive an `update_*` accessor passing the field to a closure for each field |
+/// | `map` | Derive a `map_*` accessor replacing the field with a closure's output for each struct field; enum & union fields are skipped |
+/// | `option` | Derive `is_*_some`, `clear_*`, `take_*`, `*_or` & `*_or_insert_with` accessors for each `Option` field & default its `get` to `as_ref` and `set` & `with` to `some` |
+/// | `toggle` | Derive a `toggle_*` accessor for each `bool` field |
+/// | `enable` | Derive an `enable_*` accessor for each `bool` field |
+/// | `disable` | Derive a `disable_*` accessor for each `bool` field |
+/// | `is_variant` | Enums only: derive an `is_*` accessor for each variant |
+/// | `as_variant` | Enums only: derive an `as_*` accessor for each non-unit variant |
+/// | `as_variant_mut` | Enums only: derive an `as_*_mut` accessor for each non-unit variant |
+/// | `into_variant` | Enums only: derive an `into_*` accessor for each non-unit variant |
 /// | `defaults(ContainerDefaults)` | Set default options |
 /// | `bounds(A: B + C, D: E)` | Add this `where` clause to the `impl` block |
+/// | `rename_all = "rule"` | Convert field names before building accessor names from them: `"snake_case"`, `"lowercase"` or `"none"` |
+/// | `strip_prefix = "prefix"` | Strip this prefix from field names before building accessor names from them. Pass a list, e.g. `strip_prefix("dw", "lpsz")`, to strip the first matching one. |
+/// | `when(ty = Pattern, ContainerDefaults)` | Set default options for fields whose type matches `Pattern`, where `_` matches any type & paths match the end of the field type's path, e.g. `when(ty = Option<_>, get(as_ref))`. These beat `defaults` & inferred options but lose to field options; earlier rules beat later ones. Can be repeated. |
+/// | `smart(SmartOptions)` | Infer `get` options from each field's type: `as_ref` for `Option`, `cp` for primitives, shared references & `NonZero*` integers and `deref` for `String`, `Vec`, `Box` & `PathBuf`. Fields setting their own `cp`, `owned`, `as_ref`, `deref`, `as_deref`, `ty`, `default`, `conv` or `map` opt out, as do all fields if `defaults(get)`, `defaults(all)` or a matching `when` rule set `cp`, `owned`, `as_ref`, `deref`, `as_deref`, `conv` or `map`. Can be used as a bare `smart`. |
+/// | `preset = name` | Seed `defaults` with a naming convention: `java_bean` (`get_foo`, `get_foo_mut`, `set_foo`, `is_foo` for `bool` fields), `rust_api` (`foo`, `foo_mut`, `set_foo`) or `c_style` (`my_struct_foo_get`). Explicitly set defaults & field options still win. |
 ///
 /// ## `ContainerDefaults`
 ///
 /// | Option | Description |
 /// | --- | --- |
 /// | `get(AccessorDefaults)` | Set default options for the `get` accessor type |
+/// | `get_bool(AccessorDefaults)` | Set default options for the `get` accessor type of `bool` fields. Falls back to `get`. |
 /// | `get_mut(AccessorDefaults)` | Set default options for the `get_mut` accessor type |
 /// | `set(AccessorDefaults)` | Set default options for the `set` accessor type |
+/// | `with(AccessorDefaults)` | Set default options for the `with` accessor type |
+/// | `replace(AccessorDefaults)` | Set default options for the `replace` accessor type |
+/// | `take(AccessorDefaults)` | Set default options for the `take` accessor type |
+/// | `swap(AccessorDefaults)` | Set default options for the `swap` accessor type |
+/// | `update(AccessorDefaults)` | Set default options for the `update` accessor type |
+/// | `map(AccessorDefaults)` | Set default options for the `map` accessor type |
+/// | `is_some(AccessorDefaults)` | Set default options for the `is_some` accessor type |
+/// | `clear(AccessorDefaults)` | Set default options for the `clear` accessor type |
+/// | `or(AccessorDefaults)` | Set default options for the `or` accessor type |
+/// | `or_insert_with(AccessorDefaults)` | Set default options for the `or_insert_with` accessor type |
+/// | `toggle(AccessorDefaults)` | Set default options for the `toggle` accessor type |
+/// | `enable(AccessorDefaults)` | Set default options for the `enable` accessor type |
+/// | `disable(AccessorDefaults)` | Set default options for the `disable` accessor type |
+/// | `is_variant(AccessorDefaults)` | Set default options for the `is_variant` accessor type |
+/// | `as_variant(AccessorDefaults)` | Set default options for the `as_variant` accessor type |
+/// | `as_variant_mut(AccessorDefaults)` | Set default options for the `as_variant_mut` accessor type |
+/// | `into_variant(AccessorDefaults)` | Set default options for the `into_variant` accessor type |
 /// | `all(AccessorDefaults)` | Set default options for all accessor types |
 ///
-/// `AccessorDefaults` is a subset of `AccessorTypeOptions`: `owned`, `const_fn`, `cp`, `ptr_deref`,
-/// `prefix`, `suffix`, `vis` & `bounds`.
+/// `AccessorDefaults` is a subset of `AccessorTypeOptions`: `owned`, `const_fn`, `cp`, `as_ref`,
+/// `deref`, `as_deref`, `into`, `some`, `returns`, `conv`, `map`, `ptr_deref`, `prefix`, `suffix`, `name_template`, `vis` & `bounds`.
+///
+/// ## `SmartOptions`
+///
+/// | Option | Description |
+/// | --- | --- |
+/// | `copy(Type, ...)` | Also infer `cp` for these types |
+/// | `deref(Type, ...)` | Also infer `deref` for these types |
+/// | `as_ref(Type, ...)` | Also infer `as_ref` for these types |
+///
+/// Types are matched by how they're spelled, e.g. `Uuid` won't match a `uuid::Uuid` field.
 #[proc_macro_derive(Accessors, attributes(access))]
 pub fn derive_accessors(input: BaseTokenStream) -> BaseTokenStream {
     syn::parse_macro_input!(input as derive_accessors::DeriveAccessors)